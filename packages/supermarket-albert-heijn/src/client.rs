@@ -1,10 +1,24 @@
+use std::{cmp::Reverse, sync::Arc};
+
 use async_trait::async_trait;
 use supermarket::{
-    Client, ClientError, Identifier,
+    AuthState, Authorization, Client, ClientError, Identifier, Secret,
+    product::Product,
+    profile::Profile,
+    purchase::PurchasedProduct,
     receipt::{Receipt, ReceiptSummary},
+    token::TokenStore,
+};
+
+use crate::internal::{
+    AlbertHeijnInternalClient, AlbertHeijnToken, ORDER_RECEIPT_PREFIX, PurchaseHistorySort, Region,
+    parse_order_date,
 };
 
-use crate::internal::{AlbertHeijnInternalClient, AlbertHeijnToken};
+/// Stable provider id, persisted in account names and matching the authenticator's supermarket id.
+pub const PROVIDER: &str = "albert-heijn";
+
+const ORDER_PAGE_SIZE: i64 = 50;
 
 pub struct AlbertHeijnClient {
     pub internal: AlbertHeijnInternalClient,
@@ -17,27 +31,105 @@ impl AlbertHeijnClient {
         }
     }
 
-    pub async fn auth_with_code(&self, code: &str) -> Result<(), ClientError> {
+    pub fn with_token_store(token_store: Arc<dyn TokenStore<AlbertHeijnToken>>) -> Self {
+        AlbertHeijnClient {
+            internal: AlbertHeijnInternalClient::with_token_store(token_store),
+        }
+    }
+
+    pub fn with_region(
+        region: Region,
+        token_store: Option<Arc<dyn TokenStore<AlbertHeijnToken>>>,
+    ) -> Self {
+        AlbertHeijnClient {
+            internal: AlbertHeijnInternalClient::with_region(region, token_store),
+        }
+    }
+
+    pub async fn auth_with_code(&self, code: Secret<String>) -> Result<(), ClientError> {
         self.internal.auth_with_code(code).await
     }
 
-    pub async fn auth_with_refresh_token(&self, refresh_token: &str) -> Result<(), ClientError> {
+    /// Clears the local tokens. Albert Heijn refresh tokens are not revoked and stay valid on the server.
+    pub async fn logout(&self) -> Result<(), ClientError> {
+        self.internal.logout().await
+    }
+
+    pub async fn auth_with_refresh_token(
+        &self,
+        refresh_token: Secret<String>,
+    ) -> Result<(), ClientError> {
         self.internal.auth_with_refresh_token(refresh_token).await
     }
 
+    pub async fn product(&self, product_id: &str) -> Result<Product, ClientError> {
+        let product_id = product_id
+            .parse::<i64>()
+            .map_err(|e| ClientError::TextError(e.to_string()))?;
+
+        Ok(self.internal.product(product_id).await?.into())
+    }
+
+    pub async fn purchase_history(&self) -> Result<Vec<PurchasedProduct>, ClientError> {
+        Ok(self
+            .internal
+            .product_purchase_history(0, 0, PurchaseHistorySort::Frequency)
+            .await?
+            .products
+            .into_iter()
+            .enumerate()
+            .map(|(rank, product)| PurchasedProduct {
+                id: product.id.to_string(),
+                name: product.title.unwrap_or_default(),
+                brand: product.brand,
+                rank,
+            })
+            .collect())
+    }
+
     pub async fn token(&self) -> AlbertHeijnToken {
         self.internal.token().await
     }
 
-    pub async fn set_token(&self, token: AlbertHeijnToken) {
+    pub async fn set_token(&self, token: AlbertHeijnToken) -> Result<(), ClientError> {
         self.internal.set_token(token).await
     }
 }
 
 #[async_trait]
 impl Client for AlbertHeijnClient {
+    fn provider(&self) -> &'static str {
+        PROVIDER
+    }
+
+    async fn auth_state(&self) -> AuthState {
+        self.internal.auth_state().await
+    }
+
+    async fn authorize(&self, authorization: Authorization) -> Result<(), ClientError> {
+        match authorization {
+            Authorization::Code { code, .. } => self.auth_with_code(code).await,
+            Authorization::RefreshToken { refresh_token } => {
+                self.auth_with_refresh_token(refresh_token).await
+            }
+        }
+    }
+
+    async fn profile(&self) -> Result<Profile, ClientError> {
+        self.internal
+            .member()
+            .await?
+            .map(Profile::from)
+            .ok_or(ClientError::TextError(String::from("No member")))
+    }
+
+    /// Lists store receipts and delivered orders, newest first.
+    ///
+    /// When the first orders page fails, `orderFulfillments` is considered unavailable for the account
+    /// and only store receipts are returned. Failures of later pages are returned as errors, so a
+    /// partial history is never mistaken for a complete one.
     async fn receipts(&self) -> Result<Vec<ReceiptSummary>, ClientError> {
-        Ok(self
+        let mut receipts: Vec<ReceiptSummary> = self
             .internal
             .receipts()
             .await?
@@ -46,14 +138,67 @@ impl Client for AlbertHeijnClient {
                 id: r.identifier(),
                 created_at: r.transaction_moment,
             })
-            .collect())
+            .collect();
+
+        let mut offset = 0;
+        loop {
+            let fulfillments = match self.internal.orders(offset, ORDER_PAGE_SIZE).await {
+                Ok(Some(fulfillments)) => fulfillments,
+                Ok(None) | Err(_) if offset == 0 => break,
+                Ok(None) => return Err(ClientError::TextError(String::from("No orders page"))),
+                Err(e) => return Err(e.into()),
+            };
+            let orders = fulfillments.result.unwrap_or_default();
+            offset += orders.len() as i64;
+
+            receipts.extend(orders.iter().filter(|o| o.is_delivered()).filter_map(|o| {
+                o.delivered_at().map(|created_at| ReceiptSummary {
+                    id: o.identifier(),
+                    created_at,
+                })
+            }));
+
+            if orders.is_empty() || !fulfillments.page.is_some_and(|page| page.has_next_page) {
+                break;
+            }
+        }
+
+        receipts.sort_by_key(|r| Reverse(r.created_at));
+
+        Ok(receipts)
     }
 
     async fn receipt(&self, receipt_id: &str) -> Result<Receipt, ClientError> {
+        if let Some(order_id) = receipt_id.strip_prefix(ORDER_RECEIPT_PREFIX) {
+            let order_id = order_id
+                .parse::<i64>()
+                .map_err(|e| ClientError::TextError(e.to_string()))?;
+            let order = self
+                .internal
+                .order(order_id)
+                .await?
+                .ok_or(ClientError::TextError(String::from("No order")))?;
+            let created_at = order
+                .delivery
+                .as_ref()
+                .and_then(|delivery| parse_order_date(&delivery.date))
+                .ok_or(ClientError::TextError(String::from("No delivery date")))?;
+
+            return Ok(Receipt {
+                id: order.identifier(),
+                created_at,
+                products: vec![],
+                vat: vec![],
+            });
+        }
+
+        let region = self.internal.region();
+
         self.internal.receipt(receipt_id).await.map(|r| Receipt {
             id: r.identifier(),
             created_at: r.transaction_moment,
             products: vec![],
+            vat: r.vat(region),
         })
 
         // TODO:
@@ -62,7 +207,7 @@ impl Client for AlbertHeijnClient {
         // - Combine a group into a receipt product
 
         // TODO:
-        // - Consider what other parts of the receipt are relevant (e.g. subtotal, VAT, total, etc.)
+        // - Consider what other parts of the receipt are relevant (e.g. subtotal, total, etc.)
     }
 }
 
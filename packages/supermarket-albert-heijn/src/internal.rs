@@ -1,10 +1,33 @@
 mod auth;
+mod basket;
+mod bonus;
 mod client;
 mod common;
+mod favorite;
+mod invoice;
+mod loyalty;
 mod member;
+mod miles;
+mod order;
 mod product;
 mod receipt;
+mod recipe;
+mod region;
 mod scalar;
+mod search;
+mod store;
 
 pub use auth::AlbertHeijnToken;
+pub use basket::BasketItem;
 pub use client::AlbertHeijnInternalClient;
+pub use favorite::FavoriteListProduct;
+pub use miles::{MilesTransaction, link_miles_transactions};
+pub use order::{ORDER_RECEIPT_PREFIX, OrderAddress, parse_order_date};
+pub use product::PurchaseHistorySort;
+pub use recipe::RecipeSort;
+pub use region::Region;
+pub use search::{
+    AlbertHeijnSearchQuery, PreviouslyBoughtSort, ProductSearchSort, SearchProduct,
+    SearchProductsSort, SearchResult,
+};
+pub use store::StoreSearch;
@@ -1,39 +1,61 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local, TimeDelta};
-use reqwest::RequestBuilder;
+use reqwest::{RequestBuilder, StatusCode};
 use serde::{Deserialize, Serialize};
 use supermarket::internal::{Auth, ClientError, JsonClient};
 use supermarket::serde::Nothing;
+use supermarket::token::TokenStore;
+use supermarket::{AuthState, Secret};
 
 const OAUTH_CLIENT_ID: &str = "appie-android";
 
 #[derive(Deserialize, Debug)]
 struct Token {
-    access_token: String,
-    refresh_token: String,
+    access_token: Secret<String>,
+    refresh_token: Secret<String>,
     expires_in: i64,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AlbertHeijnToken {
-    pub access_token: Option<(String, DateTime<Local>)>,
-    pub refresh_token: Option<String>,
+    pub access_token: Option<(Secret<String>, DateTime<Local>)>,
+    pub refresh_token: Option<Secret<String>>,
+    #[serde(default)]
+    pub anonymous: bool,
 }
 
 pub struct AlbertHeijnAuth {
     json_client: JsonClient,
-    access_token: Option<(String, DateTime<Local>)>,
-    refresh_token: Option<String>,
+    access_token: Option<(Secret<String>, DateTime<Local>)>,
+    refresh_token: Option<Secret<String>>,
+    anonymous: bool,
+    refresh_token_rejected: bool,
+    token_store: Option<Arc<dyn TokenStore<AlbertHeijnToken>>>,
+    /// Set when the stored token could not be read, so it is not overwritten until a token is set explicitly.
+    token_store_error: Option<String>,
 }
 
 impl AlbertHeijnAuth {
-    pub fn new(json_client: JsonClient) -> Self {
+    pub fn new(
+        json_client: JsonClient,
+        token_store: Option<Arc<dyn TokenStore<AlbertHeijnToken>>>,
+    ) -> Self {
+        let (token, token_store_error) = match token_store.as_ref().map(|store| store.load()) {
+            Some(Ok(token)) => (token, None),
+            Some(Err(e)) => (None, Some(format!("{e:?}"))),
+            None => (None, None),
+        };
+
         AlbertHeijnAuth {
             json_client,
-            access_token: None,
-            refresh_token: None,
+            access_token: token.as_ref().and_then(|token| token.access_token.clone()),
+            anonymous: token.as_ref().is_some_and(|token| token.anonymous),
+            refresh_token: token.and_then(|token| token.refresh_token),
+            refresh_token_rejected: false,
+            token_store,
+            token_store_error,
         }
     }
 
@@ -41,15 +63,56 @@ impl AlbertHeijnAuth {
         AlbertHeijnToken {
             access_token: self.access_token.clone(),
             refresh_token: self.refresh_token.clone(),
+            anonymous: self.anonymous,
         }
     }
 
-    pub fn set_token(&mut self, token: AlbertHeijnToken) {
+    pub fn set_token(&mut self, token: AlbertHeijnToken) -> Result<(), ClientError> {
         self.access_token = token.access_token;
         self.refresh_token = token.refresh_token;
+        self.anonymous = token.anonymous;
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+        self.save_token()
+    }
+
+    pub fn clear_token(&mut self) -> Result<(), ClientError> {
+        self.access_token = None;
+        self.refresh_token = None;
+        self.anonymous = false;
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+
+        match &self.token_store {
+            Some(token_store) => token_store.clear(),
+            None => Ok(()),
+        }
     }
 
-    fn process_token(&mut self, token: Token) -> String {
+    fn save_token(&self) -> Result<(), ClientError> {
+        match &self.token_store {
+            Some(token_store) => token_store.save(&self.token()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn auth_state(&self) -> AuthState {
+        if self.refresh_token_rejected && !self.anonymous {
+            AuthState::Expired
+        } else if self.access_token.is_none() && self.refresh_token.is_none() {
+            AuthState::Unauthenticated
+        } else if self.anonymous {
+            AuthState::Anonymous
+        } else {
+            AuthState::Authenticated
+        }
+    }
+
+    fn process_token(
+        &mut self,
+        token: Token,
+        anonymous: bool,
+    ) -> Result<Secret<String>, ClientError> {
         let access_token = token.access_token.clone();
 
         self.access_token = Some((
@@ -57,24 +120,31 @@ impl AlbertHeijnAuth {
             Local::now() + TimeDelta::seconds(token.expires_in),
         ));
         self.refresh_token = Some(token.refresh_token);
+        self.anonymous = anonymous;
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+        self.save_token()?;
 
-        access_token
+        Ok(access_token)
     }
 
-    pub async fn request_token(&mut self, code: String) -> Result<String, ClientError> {
+    pub async fn request_token(
+        &mut self,
+        code: Secret<String>,
+    ) -> Result<Secret<String>, ClientError> {
         let token = self
             .json_client
             .post::<_, _, Token>(
                 "/mobile-auth/v1/auth/token",
                 Nothing,
-                HashMap::from([("clientId", OAUTH_CLIENT_ID), ("code", &code)]),
+                HashMap::from([("clientId", OAUTH_CLIENT_ID), ("code", code.expose())]),
             )
             .await?;
 
-        Ok(self.process_token(token))
+        self.process_token(token, false)
     }
 
-    pub async fn request_anonymous_token(&mut self) -> Result<String, ClientError> {
+    pub async fn request_anonymous_token(&mut self) -> Result<Secret<String>, ClientError> {
         let token = self
             .json_client
             .post::<_, _, Token>(
@@ -84,11 +154,12 @@ impl AlbertHeijnAuth {
             )
             .await?;
 
-        Ok(self.process_token(token))
+        self.process_token(token, true)
     }
 
-    pub async fn refresh_token(&mut self) -> Result<String, ClientError> {
+    pub async fn refresh_token(&mut self) -> Result<Secret<String>, ClientError> {
         if let Some(refresh_token) = &self.refresh_token {
+            let anonymous = self.anonymous;
             let token = self
                 .json_client
                 .post::<_, _, Token>(
@@ -96,37 +167,64 @@ impl AlbertHeijnAuth {
                     Nothing,
                     HashMap::from([
                         ("clientId", OAUTH_CLIENT_ID),
-                        ("refreshToken", refresh_token),
+                        ("refreshToken", refresh_token.expose()),
                     ]),
                 )
-                .await?;
-
-            Ok(self.process_token(token))
+                .await
+                .inspect_err(|e| {
+                    // Only an explicit rejection (e.g. `invalid_grant`) expires the session, not outages or rate limits
+                    self.refresh_token_rejected = matches!(
+                        e,
+                        ClientError::StatusError(
+                            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED,
+                            _
+                        )
+                    )
+                })?;
+
+            self.process_token(token, anonymous)
         } else {
             Err(ClientError::TextError(String::from("No refresh token")))
         }
     }
+
+    /// Refreshes the access token, replacing a rejected anonymous session with a new one, as there is no
+    /// member who could log in again.
+    async fn refresh_or_anonymous_token(&mut self) -> Result<Secret<String>, ClientError> {
+        match self.refresh_token().await {
+            Err(_) if self.anonymous && self.refresh_token_rejected => {
+                self.request_anonymous_token().await
+            }
+            result => result,
+        }
+    }
 }
 
 #[async_trait]
 impl Auth for AlbertHeijnAuth {
     async fn request(&mut self, builder: RequestBuilder) -> Result<RequestBuilder, ClientError> {
+        if let Some(e) = &self.token_store_error {
+            return Err(ClientError::TextError(format!(
+                "Unreadable token store: {e}"
+            )));
+        }
+
         if let Some((access_token, expires_at)) = &self.access_token {
             if *expires_at > Local::now() {
-                Ok(builder.bearer_auth(access_token))
+                Ok(builder.bearer_auth(access_token.expose()))
             } else {
-                let access_token = self.refresh_token().await?;
+                let access_token = self.refresh_or_anonymous_token().await?;
 
-                Ok(builder.bearer_auth(access_token))
+                Ok(builder.bearer_auth(access_token.expose()))
             }
         } else if self.refresh_token.is_some() {
-            let access_token = self.refresh_token().await?;
+            let access_token = self.refresh_or_anonymous_token().await?;
 
-            Ok(builder.bearer_auth(access_token))
+            Ok(builder.bearer_auth(access_token.expose()))
         } else {
             let access_token = self.request_anonymous_token().await?;
 
-            Ok(builder.bearer_auth(access_token))
+            Ok(builder.bearer_auth(access_token.expose()))
         }
     }
 }
@@ -0,0 +1,130 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_basket.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetBasket;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/add_basket_items.graphql",
+    response_derives = "Debug"
+)]
+pub struct AddBasketItems;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/update_basket_items.graphql",
+    response_derives = "Debug"
+)]
+pub struct UpdateBasketItems;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/delete_basket_items.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteBasketItems;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/replace_basket_item.graphql",
+    response_derives = "Debug"
+)]
+pub struct ReplaceBasketItem;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/delete_basket.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteBasket;
+
+impl Identifier for get_basket::GetBasketBasketProducts {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+/// A basket line, either linked to a product or a free-text note when `product_id` is `None`.
+#[derive(Clone, Debug)]
+pub struct BasketItem {
+    pub product_id: Option<i64>,
+    pub description: Option<String>,
+    pub quantity: i64,
+}
+
+impl BasketItem {
+    pub fn product(product_id: i64, quantity: i64) -> Self {
+        BasketItem {
+            product_id: Some(product_id),
+            description: None,
+            quantity,
+        }
+    }
+
+    pub fn note(description: &str, quantity: i64) -> Self {
+        BasketItem {
+            product_id: None,
+            description: Some(description.to_string()),
+            quantity,
+        }
+    }
+}
+
+impl From<&BasketItem> for add_basket_items::BasketMutation {
+    fn from(item: &BasketItem) -> Self {
+        add_basket_items::BasketMutation {
+            description: item.description.clone(),
+            id: item.product_id,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<&BasketItem> for update_basket_items::BasketMutation {
+    fn from(item: &BasketItem) -> Self {
+        update_basket_items::BasketMutation {
+            description: item.description.clone(),
+            id: item.product_id,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<&BasketItem> for replace_basket_item::BasketMutation {
+    fn from(item: &BasketItem) -> Self {
+        replace_basket_item::BasketMutation {
+            description: item.description.clone(),
+            id: item.product_id,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<&BasketItem> for delete_basket_items::BasketDelete {
+    fn from(item: &BasketItem) -> Self {
+        delete_basket_items::BasketDelete {
+            description: item.description.clone(),
+            id: item.product_id,
+        }
+    }
+}
+
+impl From<&BasketItem> for replace_basket_item::BasketDelete {
+    fn from(item: &BasketItem) -> Self {
+        replace_basket_item::BasketDelete {
+            description: item.description.clone(),
+            id: item.product_id,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_bonus_periods.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetBonusPeriods;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_bonus_segments.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetBonusSegments;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_personal_bonus_segments.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetPersonalBonusSegments;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/allocate_targeted_offer.graphql",
+    response_derives = "Debug"
+)]
+pub struct AllocateTargetedOffer;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_missing_bonus_recommendations.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetMissingBonusRecommendations;
+
+impl Identifier for get_bonus_segments::GetBonusSegmentsBonusSegments {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl Identifier for get_bonus_segments::GetBonusSegmentsBonusSegmentsProducts {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Identifier for get_personal_bonus_segments::GetPersonalBonusSegmentsBonusSegments {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl Identifier
+    for allocate_targeted_offer::AllocateTargetedOfferTargetedOfferAllocateTargetedOffer
+{
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
@@ -1,25 +1,35 @@
 use std::sync::Arc;
 
+use reqwest::Method;
 use reqwest::header::{HeaderMap, HeaderValue};
-use serde::Serialize;
 use supermarket::internal::{
     Auth, ClientError, GraphQLClient, GraphQLClientError, JsonClient, NoAuth,
 };
 use supermarket::serde::Nothing;
+use supermarket::token::TokenStore;
+use supermarket::{AuthState, Secret};
 use tokio::sync::Mutex;
 
 use crate::internal::auth::{AlbertHeijnAuth, AlbertHeijnToken};
+use crate::internal::basket::*;
+use crate::internal::bonus::*;
+use crate::internal::common::mutation_result;
+use crate::internal::favorite::*;
+use crate::internal::invoice::*;
+use crate::internal::loyalty::*;
 use crate::internal::member::*;
+use crate::internal::miles::*;
+use crate::internal::order::*;
 use crate::internal::product::*;
 use crate::internal::receipt::*;
-
-const API_URL: &str = "https://api.ah.nl";
-const GRAPHQL_API_URL: &str = "https://api.ah.nl/graphql";
+use crate::internal::recipe::*;
+use crate::internal::region::Region;
+use crate::internal::search::*;
+use crate::internal::store::*;
 
 const APP_NAME: &str = "Appie";
 const APP_NAME_GRAPHQL: &str = "appie-android";
 const APP_VERSION: &str = "8.60.1";
-const APP_LOCALE: &str = "nl_NL";
 
 fn new_api_client() -> reqwest::Client {
     let mut headers = HeaderMap::new();
@@ -35,11 +45,11 @@ fn new_api_client() -> reqwest::Client {
         .expect("Client should build")
 }
 
-fn new_graphql_api_client() -> reqwest::Client {
+fn new_graphql_api_client(region: Region) -> reqwest::Client {
     let mut headers = HeaderMap::new();
     headers.insert("x-client-name", HeaderValue::from_static(APP_NAME_GRAPHQL));
     headers.insert("x-client-version", HeaderValue::from_static(APP_VERSION));
-    headers.insert("x-locale", HeaderValue::from_static(APP_LOCALE));
+    headers.insert("x-locale", HeaderValue::from_static(region.locale()));
 
     reqwest::Client::builder()
         .default_headers(headers)
@@ -53,52 +63,86 @@ pub struct AlbertHeijnInternalClient {
     auth: Arc<Mutex<AlbertHeijnAuth>>,
     graphql_client: GraphQLClient,
     json_client: JsonClient,
+    region: Region,
 }
 
 impl AlbertHeijnInternalClient {
     pub fn new() -> Self {
-        let auth = Arc::new(Mutex::new(AlbertHeijnAuth::new(JsonClient::new(
-            new_api_client(),
-            API_URL,
-            Arc::new(Mutex::new(NoAuth::new())),
-        ))));
+        Self::with_region(Region::default(), None)
+    }
+
+    pub fn with_token_store(token_store: Arc<dyn TokenStore<AlbertHeijnToken>>) -> Self {
+        Self::with_region(Region::default(), Some(token_store))
+    }
+
+    pub fn with_region(
+        region: Region,
+        token_store: Option<Arc<dyn TokenStore<AlbertHeijnToken>>>,
+    ) -> Self {
+        let auth = Arc::new(Mutex::new(AlbertHeijnAuth::new(
+            JsonClient::new(
+                new_api_client(),
+                region.api_url(),
+                Arc::new(Mutex::new(NoAuth::new())),
+            ),
+            token_store,
+        )));
 
         AlbertHeijnInternalClient {
             auth: Arc::clone(&auth),
             graphql_client: GraphQLClient::new(
-                new_graphql_api_client(),
-                GRAPHQL_API_URL,
+                new_graphql_api_client(region),
+                region.graphql_api_url(),
                 Arc::clone(&auth) as Arc<Mutex<dyn Auth + Send>>,
             ),
             json_client: JsonClient::new(
                 new_api_client(),
-                API_URL,
+                region.api_url(),
                 Arc::clone(&auth) as Arc<Mutex<dyn Auth + Send>>,
             ),
+            region,
         }
     }
 
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
     pub async fn token(&self) -> AlbertHeijnToken {
         self.auth.lock().await.token()
     }
 
-    pub async fn set_token(&self, token: AlbertHeijnToken) {
+    pub async fn auth_state(&self) -> AuthState {
+        self.auth.lock().await.auth_state()
+    }
+
+    pub async fn set_token(&self, token: AlbertHeijnToken) -> Result<(), ClientError> {
         self.auth.lock().await.set_token(token)
     }
 
-    pub async fn auth_with_code(&self, code: &str) -> Result<(), ClientError> {
+    pub async fn auth_with_code(&self, code: Secret<String>) -> Result<(), ClientError> {
         let mut auth = self.auth.lock().await;
-        auth.request_token(code.to_string()).await?;
+        auth.request_token(code).await?;
 
         Ok(())
     }
 
-    pub async fn auth_with_refresh_token(&self, refresh_token: &str) -> Result<(), ClientError> {
+    /// Clears the local tokens. The mobile auth API has no known revocation endpoint, so the refresh
+    /// token stays valid on the server until it expires.
+    pub async fn logout(&self) -> Result<(), ClientError> {
+        self.auth.lock().await.clear_token()
+    }
+
+    pub async fn auth_with_refresh_token(
+        &self,
+        refresh_token: Secret<String>,
+    ) -> Result<(), ClientError> {
         let mut auth = self.auth.lock().await;
         auth.set_token(AlbertHeijnToken {
             access_token: None,
-            refresh_token: Some(refresh_token.to_string()),
-        });
+            refresh_token: Some(refresh_token),
+            anonymous: false,
+        })?;
         auth.refresh_token().await?;
 
         Ok(())
@@ -113,6 +157,831 @@ impl AlbertHeijnInternalClient {
         Ok(response.data.unwrap().member)
     }
 
+    pub async fn basket(&self) -> Result<Option<get_basket::GetBasketBasket>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetBasket>(get_basket::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().basket)
+    }
+
+    pub async fn add_basket_items(&self, items: &[BasketItem]) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<AddBasketItems>(add_basket_items::Variables {
+                items: items.iter().map(Into::into).collect(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().basket_items_add;
+        mutation_result(
+            matches!(
+                result.status,
+                add_basket_items::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn update_basket_items(
+        &self,
+        items: &[BasketItem],
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<UpdateBasketItems>(update_basket_items::Variables {
+                items: items.iter().map(Into::into).collect(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().basket_items_update;
+        mutation_result(
+            matches!(
+                result.status,
+                update_basket_items::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn delete_basket_items(
+        &self,
+        items: &[BasketItem],
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DeleteBasketItems>(delete_basket_items::Variables {
+                items: items.iter().map(Into::into).collect(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().basket_items_delete;
+        mutation_result(
+            matches!(
+                result.status,
+                delete_basket_items::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn replace_basket_item(
+        &self,
+        old_item: &BasketItem,
+        new_item: &BasketItem,
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<ReplaceBasketItem>(replace_basket_item::Variables {
+                old_item: old_item.into(),
+                new_item: new_item.into(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().basket_item_replace;
+        mutation_result(
+            matches!(
+                result.status,
+                replace_basket_item::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn delete_basket(&self) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DeleteBasket>(delete_basket::Variables {})
+            .await?;
+
+        let result = response.data.unwrap().basket_delete;
+        mutation_result(
+            matches!(result.status, delete_basket::MutationResultStatus::SUCCESS),
+            result.error_message,
+        )
+    }
+
+    pub async fn bonus_periods(
+        &self,
+        date: Option<&str>,
+    ) -> Result<Vec<get_bonus_periods::GetBonusPeriodsBonusPeriods>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetBonusPeriods>(get_bonus_periods::Variables {
+                date: date.map(str::to_string),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().bonus_periods)
+    }
+
+    pub async fn bonus_segments(
+        &self,
+        period_start: &str,
+        period_end: &str,
+    ) -> Result<Vec<get_bonus_segments::GetBonusSegmentsBonusSegments>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetBonusSegments>(get_bonus_segments::Variables {
+                period_start: Some(period_start.to_string()),
+                period_end: Some(period_end.to_string()),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().bonus_segments)
+    }
+
+    /// Personal bonus offers for the member, both activated and not activated yet (`ACTIVATABLE`). Without
+    /// explicit states the API leaves out activatable offers.
+    ///
+    /// Listed offers cannot be activated by id: the schema has no activation mutation for a segment, and
+    /// [`Self::allocate_targeted_offer`] allocates the next offer of a loyalty program, unrelated to these.
+    pub async fn personal_offers(
+        &self,
+        start: Option<&str>,
+        end: Option<&str>,
+    ) -> Result<
+        Vec<get_personal_bonus_segments::GetPersonalBonusSegmentsBonusSegments>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetPersonalBonusSegments>(get_personal_bonus_segments::Variables {
+                period_start: start.map(|start| start.to_string()),
+                period_end: end.map(|end| end.to_string()),
+                states: Some(vec![
+                    get_personal_bonus_segments::BonusSegmentState::ACTIVATABLE,
+                    get_personal_bonus_segments::BonusSegmentState::ACTIVATED,
+                ]),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().bonus_segments)
+    }
+
+    /// Allocates the next targeted offer of a loyalty program, see [`Self::loyalty_programs`]. The server picks
+    /// the offer, so this cannot activate a specific offer from [`Self::personal_offers`].
+    pub async fn allocate_targeted_offer(
+        &self,
+        program_id: i64,
+    ) -> Result<
+        Option<allocate_targeted_offer::AllocateTargetedOfferTargetedOfferAllocateTargetedOffer>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<AllocateTargetedOffer>(allocate_targeted_offer::Variables { program_id })
+            .await?;
+
+        let result = response.data.unwrap().targeted_offer_allocate;
+        mutation_result(
+            matches!(
+                result.status,
+                allocate_targeted_offer::MutationResultStatus::SUCCESS
+            ),
+            result
+                .error_message
+                .or_else(|| result.error_reason.map(|reason| format!("{reason:?}"))),
+        )?;
+
+        Ok(result.targeted_offer)
+    }
+
+    /// Bonus products the member usually buys but which are not in the current order or basket.
+    pub async fn missing_bonus_recommendations(
+        &self,
+    ) -> Result<
+        Vec<get_missing_bonus_recommendations::GetMissingBonusRecommendationsRecommendationsMissingBonus>,
+        GraphQLClientError,
+    >{
+        let response = self
+            .graphql_client
+            .query::<GetMissingBonusRecommendations>(
+                get_missing_bonus_recommendations::Variables {},
+            )
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .recommendations_missing_bonus
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    pub async fn favorite_lists(
+        &self,
+    ) -> Result<Vec<get_favorite_lists::GetFavoriteListsFavoriteList>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetFavoriteLists>(get_favorite_lists::Variables { ids: None })
+            .await?;
+
+        Ok(response.data.unwrap().favorite_list.unwrap_or_default())
+    }
+
+    pub async fn favorite_list(
+        &self,
+        list_id: i64,
+    ) -> Result<Option<get_favorite_lists::GetFavoriteListsFavoriteList>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetFavoriteLists>(get_favorite_lists::Variables {
+                ids: Some(vec![list_id]),
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .favorite_list
+            .unwrap_or_default()
+            .into_iter()
+            .next())
+    }
+
+    pub async fn add_favorite_list(
+        &self,
+        description: &str,
+        products: &[FavoriteListProduct],
+    ) -> Result<i64, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<AddFavoriteList>(add_favorite_list::Variables {
+                description: description.to_string(),
+                products: Some(products.iter().map(Into::into).collect()),
+            })
+            .await?;
+
+        let result = response.data.unwrap().favorite_list_add;
+        mutation_result(
+            matches!(
+                result.status,
+                add_favorite_list::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )?;
+
+        result
+            .result
+            .map(|list| list.id)
+            .ok_or(GraphQLClientError::TextError(String::from(
+                "No favorite list",
+            )))
+    }
+
+    pub async fn rename_favorite_list(
+        &self,
+        list_id: i64,
+        description: &str,
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<ChangeFavoriteList>(change_favorite_list::Variables {
+                id: list_id,
+                description: description.to_string(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().favorite_list_change;
+        mutation_result(
+            matches!(
+                result.status,
+                change_favorite_list::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn delete_favorite_list(&self, list_id: i64) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DeleteFavoriteList>(delete_favorite_list::Variables { id: list_id })
+            .await?;
+
+        let result = response.data.unwrap().favorite_list_delete;
+        mutation_result(
+            matches!(
+                result.status,
+                delete_favorite_list::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn add_favorite_list_products(
+        &self,
+        list_id: i64,
+        products: &[FavoriteListProduct],
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<AddFavoriteListProducts>(add_favorite_list_products::Variables {
+                id: list_id,
+                products: products.iter().map(Into::into).collect(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().favorite_list_products_add;
+        mutation_result(
+            matches!(
+                result.status,
+                add_favorite_list_products::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn delete_favorite_list_products(
+        &self,
+        list_id: i64,
+        item_ids: &[i64],
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DeleteFavoriteListProducts>(delete_favorite_list_products::Variables {
+                id: list_id,
+                item_ids: item_ids.to_vec(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().favorite_list_products_delete;
+        mutation_result(
+            matches!(
+                result.status,
+                delete_favorite_list_products::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn miles_balance(
+        &self,
+    ) -> Result<get_miles_balance::GetMilesBalanceMilesBalance, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetMilesBalance>(get_miles_balance::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().miles_balance)
+    }
+
+    /// Use [`link_miles_transactions`] with [`Self::receipts`] to find the receipt of each transaction.
+    pub async fn miles_transactions(
+        &self,
+    ) -> Result<
+        Vec<get_miles_transactions::GetMilesTransactionsMilesTransactions>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetMilesTransactions>(get_miles_transactions::Variables {})
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .miles_transactions
+            .unwrap_or_default())
+    }
+
+    pub async fn miles_charities(
+        &self,
+    ) -> Result<Vec<get_miles_charities::GetMilesCharitiesMilesCharities>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetMilesCharities>(get_miles_charities::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().miles_charities)
+    }
+
+    /// The `request_id` is a UUID chosen by the caller, which makes retrying a donation idempotent.
+    pub async fn donate_miles(
+        &self,
+        charity_id: &str,
+        miles: i64,
+        request_id: &str,
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DonateMiles>(donate_miles::Variables {
+                charity_id: charity_id.to_string(),
+                miles,
+                request_id: request_id.to_string(),
+            })
+            .await?;
+
+        let result = response.data.unwrap().miles_donate;
+        mutation_result(
+            matches!(result.status, donate_miles::MutationResultStatus::SUCCESS),
+            result.error_message,
+        )
+    }
+
+    pub async fn orders(
+        &self,
+        offset: i64,
+        size: i64,
+    ) -> Result<Option<get_orders::GetOrdersOrderFulfillments>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetOrders>(get_orders::Variables {
+                offset: Some(offset),
+                size: Some(size),
+                status: Some(get_orders::FulfillmentStatus::ALL),
+                include_cancelled_orders: Some(true),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().order_fulfillments)
+    }
+
+    pub async fn order(
+        &self,
+        order_id: i64,
+    ) -> Result<Option<get_order::GetOrderOrder>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetOrder>(get_order::Variables { id: Some(order_id) })
+            .await?;
+
+        Ok(response.data.unwrap().order)
+    }
+
+    pub async fn order_receipt(
+        &self,
+        order_id: i64,
+    ) -> Result<Option<get_order_receipt::GetOrderReceiptOrderReceipt>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetOrderReceipt>(get_order_receipt::Variables {
+                order_id: Some(order_id),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().order_receipt)
+    }
+
+    pub async fn invoices(
+        &self,
+        order_id: Option<i64>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Option<get_invoices::GetInvoicesInvoices>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetInvoices>(get_invoices::Variables {
+                order_id,
+                offset: Some(offset),
+                limit: Some(limit),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().invoices)
+    }
+
+    /// Downloads the PDF document of an invoice. The URL may be absolute or relative to the API host,
+    /// but must resolve to the API host itself, so the member's token is never sent elsewhere. Invoice
+    /// URLs on any other host (e.g. a CDN) are rejected.
+    pub async fn invoice_document(&self, url: &str) -> Result<Vec<u8>, ClientError> {
+        let path = invoice_document_path(self.region.api_url(), url).ok_or_else(|| {
+            ClientError::TextError(format!("Unsupported invoice document URL {url}"))
+        })?;
+
+        let response = self
+            .json_client
+            .send(Method::GET, &path, Nothing, Nothing)
+            .await?;
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(ClientError::RequestError)
+    }
+
+    /// Cards, gift cards and purchase stamps used to pay for an online order.
+    pub async fn order_transactions(
+        &self,
+        order_id: i64,
+    ) -> Result<
+        Option<get_order_transactions::GetOrderTransactionsPaymentsGetOrderTransactions>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetOrderTransactions>(get_order_transactions::Variables { order_id })
+            .await?;
+
+        Ok(response.data.unwrap().payments_get_order_transactions)
+    }
+
+    pub async fn delivery_slots(
+        &self,
+        address: &OrderAddress,
+    ) -> Result<Vec<get_delivery_slots::GetDeliverySlotsOrderDeliverySlots>, GraphQLClientError>
+    {
+        let response = self
+            .graphql_client
+            .query::<GetDeliverySlots>(get_delivery_slots::Variables {
+                address: address.into(),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().order_delivery_slots)
+    }
+
+    pub async fn pickup_slots(
+        &self,
+        pickup_location_id: i64,
+    ) -> Result<Vec<get_pickup_slots::GetPickupSlotsOrderPickupSlots>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetPickupSlots>(get_pickup_slots::Variables { pickup_location_id })
+            .await?;
+
+        Ok(response.data.unwrap().order_pickup_slots)
+    }
+
+    pub async fn purchase_stamp_balance(
+        &self,
+    ) -> Result<
+        get_purchase_stamp_balance::GetPurchaseStampBalancePurchaseStampBalance,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetPurchaseStampBalance>(get_purchase_stamp_balance::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().purchase_stamp_balance)
+    }
+
+    pub async fn purchase_stamp_transactions(
+        &self,
+        key: Option<&str>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<
+        get_purchase_stamp_transactions::GetPurchaseStampTransactionsPurchaseStampTransactions,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetPurchaseStampTransactions>(get_purchase_stamp_transactions::Variables {
+                key: key.map(str::to_string),
+                pagination: get_purchase_stamp_transactions::OffsetLimitPagination {
+                    limit,
+                    offset,
+                },
+            })
+            .await?;
+
+        Ok(response.data.unwrap().purchase_stamp_transactions)
+    }
+
+    pub async fn purchase_stamp_saving_goal(
+        &self,
+    ) -> Result<
+        Option<get_purchase_stamp_saving_goal::GetPurchaseStampSavingGoalPurchaseStampSavingGoal>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetPurchaseStampSavingGoal>(get_purchase_stamp_saving_goal::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().purchase_stamp_saving_goal)
+    }
+
+    pub async fn set_purchase_stamp_saving_goal(
+        &self,
+        name: &str,
+        amount: i64,
+    ) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SetPurchaseStampSavingGoal>(set_purchase_stamp_saving_goal::Variables {
+                name: name.to_string(),
+                amount,
+            })
+            .await?;
+
+        let result = response.data.unwrap().purchase_stamp_saving_goal_set;
+        mutation_result(
+            matches!(
+                result.status,
+                set_purchase_stamp_saving_goal::MutationResultStatus::SUCCESS
+            ),
+            result.error_message,
+        )
+    }
+
+    pub async fn loyalty_programs(
+        &self,
+    ) -> Result<Vec<get_loyalty_programs::GetLoyaltyProgramsLoyaltyPrograms>, GraphQLClientError>
+    {
+        let response = self
+            .graphql_client
+            .query::<GetLoyaltyPrograms>(get_loyalty_programs::Variables {
+                program_types: None,
+            })
+            .await?;
+
+        Ok(response.data.unwrap().loyalty_programs)
+    }
+
+    pub async fn loyalty_points_balances(
+        &self,
+        program_ids: &[i64],
+    ) -> Result<
+        Vec<get_loyalty_points_balances::GetLoyaltyPointsBalancesLoyaltyPointsBalances>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetLoyaltyPointsBalances>(get_loyalty_points_balances::Variables {
+                program_ids: program_ids.to_vec(),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().loyalty_points_balances)
+    }
+
+    pub async fn loyalty_points_transactions(
+        &self,
+        program_id: i64,
+        key: Option<&str>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<
+        get_loyalty_points_transactions::GetLoyaltyPointsTransactionsLoyaltyPointsTransactions,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetLoyaltyPointsTransactions>(get_loyalty_points_transactions::Variables {
+                program_id,
+                key: key.map(str::to_string),
+                pagination: get_loyalty_points_transactions::OffsetLimitPagination {
+                    limit,
+                    offset,
+                },
+            })
+            .await?;
+
+        Ok(response.data.unwrap().loyalty_points_transactions)
+    }
+
+    pub async fn product(
+        &self,
+        product_id: i64,
+    ) -> Result<get_product::GetProductProduct, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetProduct>(get_product::Variables { id: product_id })
+            .await?;
+
+        Ok(response.data.unwrap().product)
+    }
+
+    /// Pass a `size` of zero to fetch the complete history as a single page.
+    pub async fn product_purchase_history(
+        &self,
+        page: i64,
+        size: i64,
+        sort: PurchaseHistorySort,
+    ) -> Result<
+        get_product_purchase_history::GetProductPurchaseHistoryProductPurchaseHistory,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetProductPurchaseHistory>(get_product_purchase_history::Variables {
+                page: Some(page),
+                size: Some(size),
+                sort_by: Some(sort.into()),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().product_purchase_history)
+    }
+
+    pub async fn search_recipes(
+        &self,
+        query: &str,
+        sort: RecipeSort,
+        start: i64,
+        size: i64,
+    ) -> Result<search_recipes::SearchRecipesRecipeSearchV2, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SearchRecipes>(search_recipes::Variables {
+                search_text: Some(query.to_string()),
+                sort_by: Some(sort.into()),
+                start: Some(start),
+                size: Some(size),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().recipe_search_v2)
+    }
+
+    /// Ingredient quantities are scaled to `servings` when given, otherwise to the recipe's default.
+    pub async fn recipe(
+        &self,
+        recipe_id: i64,
+        servings: Option<i64>,
+    ) -> Result<Option<get_recipe::GetRecipeRecipe>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetRecipe>(get_recipe::Variables {
+                id: recipe_id,
+                servings,
+            })
+            .await?;
+
+        Ok(response.data.unwrap().recipe)
+    }
+
+    /// Resolves each ingredient of a recipe to a suggested product and the quantity to buy for `servings`.
+    pub async fn recipe_products(
+        &self,
+        recipe_id: i64,
+        servings: Option<i64>,
+    ) -> Result<
+        Vec<get_recipe_products::GetRecipeProductsRecipeProductSuggestionsV2>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetRecipeProducts>(get_recipe_products::Variables {
+                recipe_id,
+                servings,
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .recipe_product_suggestions_v2
+            .unwrap_or_default())
+    }
+
+    /// Similar products, e.g. to replace one that is out of stock or discontinued.
+    pub async fn product_alternatives(
+        &self,
+        product_id: i64,
+        size: i64,
+    ) -> Result<
+        Vec<get_product_alternatives::GetProductAlternativesProductAlternativesProducts>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetProductAlternatives>(get_product_alternatives::Variables {
+                id: product_id,
+                size,
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .product_alternatives
+            .and_then(|recommendations| recommendations.products)
+            .unwrap_or_default())
+    }
+
+    pub async fn product_cross_sells(
+        &self,
+        product_id: i64,
+        offset: i64,
+        limit: i64,
+    ) -> Result<
+        Vec<get_product_cross_sells::GetProductCrossSellsProductCrossSellsV2Products>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetProductCrossSells>(get_product_cross_sells::Variables {
+                product_id,
+                offset,
+                limit,
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .product_cross_sells_v2
+            .and_then(|recommendations| recommendations.products)
+            .unwrap_or_default())
+    }
+
     pub async fn product_categories(&self) -> Result<Vec<ProductCategory>, ClientError> {
         self.json_client
             .get::<_, Vec<ProductCategory>>(
@@ -157,20 +1026,258 @@ impl AlbertHeijnInternalClient {
 
     // TODO: return a paginator that can make requests instead of directly performing a single one
 
-    pub async fn search_products<Q: Serialize>(
+    pub async fn search_products(
         &self,
-        query: Q,
+        query: &AlbertHeijnSearchQuery,
     ) -> Result<ProductSearch, ClientError> {
         self.json_client
-            .get::<Q, ProductSearch>("/mobile-services/product/search/v2", query)
+            .get::<_, ProductSearch>("/mobile-services/product/search/v2", query)
             .await
     }
 
+    /// GraphQL counterpart of [`Self::search_products`]. Prices and availability reflect `store_id` when given.
+    pub async fn search_products_graphql(
+        &self,
+        query: &str,
+        sort: SearchProductsSort,
+        page: i64,
+        size: i64,
+        store_id: Option<i64>,
+    ) -> Result<SearchResult, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SearchProducts>(search_products::Variables {
+                query: query.to_string(),
+                sort_type: Some(sort.into()),
+                page: Some(page),
+                size: Some(size),
+                intent: store_id.map(|store_id| search_products::SearchIntentInput {
+                    favourite_store: Some(store_id),
+                    intent: search_products::SearchIntentType::VISIT_STORE,
+                    order_id: None,
+                }),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().search_products.into())
+    }
+
+    /// Prices and availability reflect `store_id` when given.
+    pub async fn search_previously_bought(
+        &self,
+        sort: PreviouslyBoughtSort,
+        page: i64,
+        size: i64,
+        store_id: Option<i64>,
+    ) -> Result<SearchResult, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SearchPreviouslyBought>(search_previously_bought::Variables {
+                sort_type: Some(sort.into()),
+                page: Some(page),
+                size: Some(size),
+                intent: store_id.map(|store_id| search_previously_bought::SearchIntentInput {
+                    favourite_store: Some(store_id),
+                    intent: search_previously_bought::SearchIntentType::VISIT_STORE,
+                    order_id: None,
+                }),
+            })
+            .await?;
+
+        Ok(response.data.unwrap().search_previously_bought.into())
+    }
+
     pub async fn search_products_by_category(
         &self,
         category_id: &str,
     ) -> Result<ProductSearch, ClientError> {
-        self.search_products([["taxonomyId", category_id]]).await
+        self.search_products(&AlbertHeijnSearchQuery::new().taxonomy(category_id))
+            .await
+    }
+
+    pub async fn search_stores(
+        &self,
+        search: &StoreSearch,
+        start: i64,
+        limit: i64,
+    ) -> Result<Vec<search_stores::SearchStoresStoresSearchResult>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SearchStores>(search_stores::Variables {
+                filter: Some(search.into()),
+                start: Some(start),
+                limit: Some(limit),
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .stores_search
+            .result
+            .unwrap_or_default())
+    }
+
+    pub async fn store_location_suggestions(
+        &self,
+        query: &str,
+    ) -> Result<
+        Vec<
+            get_store_location_suggestions::GetStoreLocationSuggestionsStoresGeoLocationSuggestions,
+        >,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetStoreLocationSuggestions>(get_store_location_suggestions::Variables {
+                search: query.to_string(),
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .stores_geo_location_suggestions
+            .unwrap_or_default())
+    }
+
+    pub async fn store(
+        &self,
+        store_id: i64,
+    ) -> Result<Option<get_store::GetStoreStoresInformation>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetStore>(get_store::Variables { id: store_id })
+            .await?;
+
+        Ok(response.data.unwrap().stores_information)
+    }
+
+    pub async fn favourite_store(
+        &self,
+    ) -> Result<
+        Option<get_favourite_store::GetFavouriteStoreStoresFavouriteStore>,
+        GraphQLClientError,
+    > {
+        let response = self
+            .graphql_client
+            .query::<GetFavouriteStore>(get_favourite_store::Variables {})
+            .await?;
+
+        Ok(response.data.unwrap().stores_favourite_store)
+    }
+
+    pub async fn set_favourite_store(&self, store_id: i64) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<SetFavouriteStore>(set_favourite_store::Variables { id: Some(store_id) })
+            .await?;
+
+        match response.data.unwrap().stores_set_favourite_store {
+            Some(result) => mutation_result(
+                matches!(
+                    result.status,
+                    set_favourite_store::MutationResultStatus::SUCCESS
+                ),
+                result.error_message,
+            ),
+            None => Err(GraphQLClientError::TextError(format!(
+                "Store {store_id} was not set as favourite store"
+            ))),
+        }
+    }
+
+    pub async fn store_stocks(
+        &self,
+        store_id: i64,
+        product_ids: &[i64],
+    ) -> Result<Vec<get_store_stocks::GetStoreStocksStoreStocks>, GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<GetStoreStocks>(get_store_stocks::Variables {
+                store_id,
+                product_ids: product_ids.to_vec(),
+            })
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .store_stocks
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    pub async fn store_stock(
+        &self,
+        store_id: i64,
+        product_id: i64,
+    ) -> Result<Option<get_store_stocks::GetStoreStocksStoreStocks>, GraphQLClientError> {
+        Ok(self
+            .store_stocks(store_id, &[product_id])
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    /// Products in stock at the store that can replace `product_id`.
+    pub async fn store_stock_alternative_products(
+        &self,
+        store_id: i64,
+        product_id: i64,
+    ) -> Result<
+        Vec<get_store_stock_alternative_products::GetStoreStockAlternativeProductsStoreStockAlternativeProducts>,
+        GraphQLClientError,
+    >{
+        let response = self
+            .graphql_client
+            .query::<GetStoreStockAlternativeProducts>(
+                get_store_stock_alternative_products::Variables {
+                    store_id,
+                    product_id,
+                },
+            )
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .store_stock_alternative_products
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    /// Stores near `store_id` that have `product_id` in stock.
+    pub async fn store_stock_alternative_stores(
+        &self,
+        store_id: i64,
+        product_id: i64,
+    ) -> Result<
+        Vec<get_store_stock_alternative_stores::GetStoreStockAlternativeStoresStoreStockAlternativeStores>,
+        GraphQLClientError,
+    >{
+        let response = self
+            .graphql_client
+            .query::<GetStoreStockAlternativeStores>(
+                get_store_stock_alternative_stores::Variables {
+                    store_id,
+                    product_id,
+                },
+            )
+            .await?;
+
+        Ok(response
+            .data
+            .unwrap()
+            .store_stock_alternative_stores
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .collect())
     }
 
     pub async fn receipts(&self) -> Result<Vec<ReceiptSummary>, ClientError> {
@@ -192,6 +1299,26 @@ impl AlbertHeijnInternalClient {
 
         Ok(receipt)
     }
+
+    /// Deletes a store receipt. Fails with the reason reported by the API when the receipt was not deleted.
+    pub async fn delete_receipt(&self, receipt_id: &str) -> Result<(), GraphQLClientError> {
+        let response = self
+            .graphql_client
+            .query::<DeleteReceipt>(delete_receipt::Variables {
+                id: receipt_id.to_string(),
+            })
+            .await?;
+
+        match response.data.unwrap().store_receipt_delete {
+            Some(result) => mutation_result(
+                matches!(result.status, delete_receipt::MutationResultStatus::SUCCESS),
+                result.error_message,
+            ),
+            None => Err(GraphQLClientError::TextError(format!(
+                "Receipt {receipt_id} was not deleted"
+            ))),
+        }
+    }
 }
 
 impl Default for AlbertHeijnInternalClient {
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use supermarket::internal::GraphQLClientError;
 
 #[derive(Deserialize, Debug)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -49,3 +50,16 @@ pub struct Page {
     pub total_elements: u64,
     pub total_pages: u64,
 }
+
+pub fn mutation_result(
+    success: bool,
+    error_message: Option<String>,
+) -> Result<(), GraphQLClientError> {
+    if success {
+        Ok(())
+    } else {
+        Err(GraphQLClientError::TextError(
+            error_message.unwrap_or_else(|| String::from("Mutation failed")),
+        ))
+    }
+}
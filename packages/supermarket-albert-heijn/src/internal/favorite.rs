@@ -0,0 +1,86 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_favorite_lists.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetFavoriteLists;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/add_favorite_list.graphql",
+    response_derives = "Debug"
+)]
+pub struct AddFavoriteList;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/change_favorite_list.graphql",
+    response_derives = "Debug"
+)]
+pub struct ChangeFavoriteList;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/delete_favorite_list.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteFavoriteList;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/add_favorite_list_products.graphql",
+    response_derives = "Debug"
+)]
+pub struct AddFavoriteListProducts;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/delete_favorite_list_products.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteFavoriteListProducts;
+
+impl Identifier for get_favorite_lists::GetFavoriteListsFavoriteList {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Identifier for get_favorite_lists::GetFavoriteListsFavoriteListItems {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FavoriteListProduct {
+    pub product_id: i64,
+    pub quantity: Option<i64>,
+}
+
+impl From<&FavoriteListProduct> for add_favorite_list::FavoriteListProductMutation {
+    fn from(product: &FavoriteListProduct) -> Self {
+        add_favorite_list::FavoriteListProductMutation {
+            product_id: product.product_id,
+            quantity: product.quantity,
+        }
+    }
+}
+
+impl From<&FavoriteListProduct> for add_favorite_list_products::FavoriteListProductMutation {
+    fn from(product: &FavoriteListProduct) -> Self {
+        add_favorite_list_products::FavoriteListProductMutation {
+            product_id: product.product_id,
+            quantity: product.quantity,
+        }
+    }
+}
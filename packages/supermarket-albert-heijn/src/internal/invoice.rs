@@ -0,0 +1,79 @@
+use graphql_client::GraphQLQuery;
+use reqwest::Url;
+use supermarket::Identifier;
+
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_invoices.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetInvoices;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_order_transactions.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetOrderTransactions;
+
+impl Identifier for get_invoices::GetInvoicesInvoicesResults {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+/// Resolves an invoice URL against the API URL and returns its path and query, or `None` when it
+/// points to another scheme, host or port.
+pub fn invoice_document_path(api_url: &str, url: &str) -> Option<String> {
+    let api_url = Url::parse(api_url).ok()?;
+    let document_url = api_url.join(url).ok()?;
+
+    if document_url.scheme() != api_url.scheme()
+        || document_url.host_str() != api_url.host_str()
+        || document_url.port_or_known_default() != api_url.port_or_known_default()
+    {
+        return None;
+    }
+
+    Some(match document_url.query() {
+        Some(query) => format!("{}?{query}", document_url.path()),
+        None => document_url.path().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const API_URL: &str = "https://api.ah.nl";
+
+    #[test]
+    fn invoice_document_path_on_api_host() {
+        assert_eq!(
+            invoice_document_path(API_URL, "https://api.ah.nl/invoices/1.pdf?token=a").as_deref(),
+            Some("/invoices/1.pdf?token=a")
+        );
+        assert_eq!(
+            invoice_document_path(API_URL, "/invoices/1.pdf").as_deref(),
+            Some("/invoices/1.pdf")
+        );
+    }
+
+    #[test]
+    fn invoice_document_path_on_other_host() {
+        for url in [
+            "https://api.ah.nl.evil.com/x",
+            "https://api.ah.nl@evil.com/x",
+            "//evil.com/x",
+            "http://api.ah.nl/x",
+            "https://api.ah.nl:8443/x",
+            "https://api.ah.be/x",
+        ] {
+            assert_eq!(invoice_document_path(API_URL, url), None, "{url}");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_purchase_stamp_balance.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetPurchaseStampBalance;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_purchase_stamp_transactions.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetPurchaseStampTransactions;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_purchase_stamp_saving_goal.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetPurchaseStampSavingGoal;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/set_purchase_stamp_saving_goal.graphql",
+    response_derives = "Debug"
+)]
+pub struct SetPurchaseStampSavingGoal;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_loyalty_programs.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetLoyaltyPrograms;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_loyalty_points_balances.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetLoyaltyPointsBalances;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_loyalty_points_transactions.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetLoyaltyPointsTransactions;
+
+impl Identifier for get_loyalty_programs::GetLoyaltyProgramsLoyaltyPrograms {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
@@ -1,5 +1,6 @@
 use graphql_client::GraphQLQuery;
 use supermarket::Identifier;
+use supermarket::profile::{Profile, ProfileAddress, ProfileCard, ProfilePreference};
 
 use crate::internal::scalar::*;
 
@@ -18,3 +19,77 @@ impl Identifier for GetMemberMember {
         self.id.to_string()
     }
 }
+
+impl From<GetMemberMember> for Profile {
+    fn from(member: GetMemberMember) -> Self {
+        let id = member.identifier();
+        let cards = member
+            .cards
+            .map(|cards| {
+                [
+                    ("bonus", cards.bonus),
+                    ("airmiles", cards.airmiles),
+                    ("etos", cards.etos),
+                    ("gall", cards.gall),
+                    ("wijndomein", cards.wijndomein),
+                    ("xl", cards.xl),
+                ]
+                .into_iter()
+                .filter_map(|(r#type, number)| {
+                    number.map(|number| ProfileCard {
+                        r#type: r#type.to_string(),
+                        number,
+                    })
+                })
+                .collect()
+            })
+            .unwrap_or_default();
+
+        let mut preferences: Vec<ProfilePreference> = member
+            .consents
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .map(|consent| ProfilePreference {
+                code: consent.code,
+                granted: consent.granted,
+            })
+            .collect();
+        preferences.extend(
+            member
+                .contact_subscriptions
+                .unwrap_or_default()
+                .into_iter()
+                .map(|code| ProfilePreference {
+                    code,
+                    granted: true,
+                }),
+        );
+
+        Profile {
+            id,
+            email_address: member.email_address,
+            first_name: member.name.as_ref().and_then(|name| name.first.clone()),
+            last_name: member.name.and_then(|name| name.last),
+            phone_numbers: member.phone_number.into_iter().collect(),
+            addresses: member
+                .address
+                .map(|address| ProfileAddress {
+                    street: address.street,
+                    house_number: address.house_number.map(|house_number| {
+                        format!(
+                            "{house_number}{}",
+                            address.house_number_extra.unwrap_or_default()
+                        )
+                    }),
+                    postal_code: address.postal_code,
+                    city: address.city,
+                    country_code: address.country_code,
+                })
+                .into_iter()
+                .collect(),
+            cards,
+            preferences,
+        }
+    }
+}
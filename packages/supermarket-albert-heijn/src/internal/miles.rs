@@ -0,0 +1,87 @@
+use chrono::NaiveDate;
+use chrono_tz::Europe::Amsterdam;
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+use crate::internal::receipt::ReceiptSummary;
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_miles_balance.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetMilesBalance;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_miles_transactions.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetMilesTransactions;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_miles_charities.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetMilesCharities;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/donate_miles.graphql",
+    response_derives = "Debug"
+)]
+pub struct DonateMiles;
+
+impl Identifier for get_miles_charities::GetMilesCharitiesMilesCharities {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct MilesTransaction {
+    pub transaction: get_miles_transactions::GetMilesTransactionsMilesTransactions,
+    pub receipt_id: Option<String>,
+}
+
+/// Links each transaction to the store receipt from the same day, but only when that day has a single receipt.
+pub fn link_miles_transactions(
+    transactions: Vec<get_miles_transactions::GetMilesTransactionsMilesTransactions>,
+    receipts: &[ReceiptSummary],
+) -> Vec<MilesTransaction> {
+    transactions
+        .into_iter()
+        .map(|transaction| {
+            let date = transaction
+                .date
+                .as_deref()
+                .and_then(|date| date.get(..10))
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+
+            let mut matches = receipts.iter().filter(|receipt| {
+                Some(
+                    receipt
+                        .transaction_moment
+                        .with_timezone(&Amsterdam)
+                        .date_naive(),
+                ) == date
+            });
+
+            let receipt_id = match (matches.next(), matches.next()) {
+                (Some(receipt), None) => Some(receipt.identifier()),
+                _ => None,
+            };
+
+            MilesTransaction {
+                transaction,
+                receipt_id,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Europe::Amsterdam;
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_orders.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetOrders;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_order.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetOrder;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_order_receipt.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetOrderReceipt;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_delivery_slots.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetDeliverySlots;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_pickup_slots.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetPickupSlots;
+
+pub const ORDER_RECEIPT_PREFIX: &str = "order-";
+
+/// Parses a local delivery date as the start of that day in Amsterdam.
+pub fn parse_order_date(date: &str) -> Option<chrono::DateTime<Utc>> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|date| date.and_local_timezone(Amsterdam).earliest())
+        .map(|date| date.with_timezone(&Utc))
+}
+
+impl get_orders::GetOrdersOrderFulfillmentsResult {
+    pub fn is_delivered(&self) -> bool {
+        self.delivery.as_ref().is_some_and(|delivery| {
+            matches!(delivery.status, get_orders::OrderDeliveryStatus::DELIVERED)
+        })
+    }
+
+    pub fn delivered_at(&self) -> Option<chrono::DateTime<Utc>> {
+        self.delivery
+            .as_ref()
+            .and_then(|delivery| delivery.slot.as_ref())
+            .and_then(|slot| parse_order_date(&slot.date))
+    }
+}
+
+impl Identifier for get_orders::GetOrdersOrderFulfillmentsResult {
+    fn identifier(&self) -> String {
+        format!("{ORDER_RECEIPT_PREFIX}{}", self.order_id)
+    }
+}
+
+impl Identifier for get_order::GetOrderOrder {
+    fn identifier(&self) -> String {
+        format!("{ORDER_RECEIPT_PREFIX}{}", self.id)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderAddress {
+    pub street: String,
+    pub house_number: i64,
+    pub house_number_extra: Option<String>,
+    pub postal_code: String,
+    pub city: String,
+    pub country_code: String,
+}
+
+impl From<&OrderAddress> for get_delivery_slots::MemberAddressInput {
+    fn from(address: &OrderAddress) -> Self {
+        get_delivery_slots::MemberAddressInput {
+            street: address.street.clone(),
+            house_number: address.house_number,
+            house_number_extra: address.house_number_extra.clone(),
+            postal_code: address.postal_code.clone(),
+            city: address.city.clone(),
+            country_code: address.country_code.clone(),
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use chrono::NaiveDate;
+use graphql_client::GraphQLQuery;
 use serde::Deserialize;
 use supermarket::Identifier;
+use supermarket::product::{
+    ProductAllergens, ProductNutrient, ProductNutrition, ProductRestriction,
+};
 
 use crate::internal::common::{Image, Links, Page};
+use crate::internal::scalar::*;
+
+use self::get_product::*;
 
 // TODO: move filter structs to a separate file?
 
@@ -152,3 +159,142 @@ impl Identifier for Product {
         self.webshop_id.to_string()
     }
 }
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_product.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetProduct;
+
+impl Identifier for GetProductProduct {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl From<GetProductProduct> for supermarket::product::Product {
+    fn from(product: GetProductProduct) -> Self {
+        let id = product.identifier();
+        let trade_item = product.trade_item;
+        let ingredients = trade_item
+            .as_ref()
+            .and_then(|trade_item| trade_item.ingredients.as_ref());
+
+        supermarket::product::Product {
+            id,
+            name: product.title.unwrap_or_default(),
+            brand: product.brand,
+            gtin: trade_item
+                .as_ref()
+                .and_then(|trade_item| trade_item.gtin.clone()),
+            ingredients: ingredients.and_then(|ingredients| ingredients.statement.clone()),
+            allergens: ingredients
+                .and_then(|ingredients| ingredients.allergens.as_ref())
+                .map(|allergens| ProductAllergens {
+                    contains: allergens
+                        .contains
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .cloned()
+                        .collect(),
+                    may_contain: allergens
+                        .may_contain
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .cloned()
+                        .collect(),
+                    free_from: allergens
+                        .free_from
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .cloned()
+                        .collect(),
+                })
+                .unwrap_or_default(),
+            nutrition: trade_item
+                .as_ref()
+                .and_then(|trade_item| trade_item.nutritions.as_ref())
+                .into_iter()
+                .flatten()
+                .map(|nutrition| ProductNutrition {
+                    basis: nutrition
+                        .basis_quantity_description
+                        .clone()
+                        .or(nutrition.basis_quantity.clone()),
+                    preparation_state: nutrition.preparation_state.clone(),
+                    nutrients: nutrition
+                        .nutrients
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .map(|nutrient| ProductNutrient {
+                            r#type: nutrient.type_.clone(),
+                            name: nutrient.name.clone(),
+                            value: nutrient.value.clone(),
+                            daily_value: nutrient.daily_value.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            restrictions: if product.age_check.unwrap_or(false) {
+                vec![ProductRestriction::AgeRestriction(18)]
+            } else {
+                vec![]
+            },
+        }
+    }
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_product_purchase_history.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetProductPurchaseHistory;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_product_alternatives.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetProductAlternatives;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_product_cross_sells.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetProductCrossSells;
+
+#[derive(Clone, Copy, Debug)]
+pub enum PurchaseHistorySort {
+    Date,
+    Department,
+    Frequency,
+    Relevance,
+}
+
+impl From<PurchaseHistorySort> for get_product_purchase_history::SortBySort {
+    fn from(sort: PurchaseHistorySort) -> Self {
+        match sort {
+            PurchaseHistorySort::Date => get_product_purchase_history::SortBySort::DESC_DATE,
+            PurchaseHistorySort::Department => {
+                get_product_purchase_history::SortBySort::ASC_DEPARTMENT
+            }
+            PurchaseHistorySort::Frequency => {
+                get_product_purchase_history::SortBySort::DESC_FREQUENCY
+            }
+            PurchaseHistorySort::Relevance => {
+                get_product_purchase_history::SortBySort::DESC_RELEVANCE
+            }
+        }
+    }
+}
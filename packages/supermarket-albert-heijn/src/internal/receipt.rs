@@ -1,8 +1,19 @@
 use chrono::{DateTime, Utc};
+use graphql_client::GraphQLQuery;
 use serde::Deserialize;
 use supermarket::Identifier;
+use supermarket::receipt::ReceiptVat;
 
 use crate::internal::common::{Address, CurrencyAmount};
+use crate::internal::region::Region;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/delete_receipt.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteReceipt;
 
 #[derive(Deserialize, Debug)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -91,6 +102,10 @@ pub enum ReceiptUiItem {
         left: String,
         right: String,
     },
+
+    /// Item types not listed above, e.g. layouts only used in some regions.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Deserialize, Debug)]
@@ -108,3 +123,113 @@ impl Identifier for Receipt {
         self.transaction_id.clone()
     }
 }
+
+impl Receipt {
+    /// VAT lines of the receipt. Rows that cannot be parsed, such as the column headers, are skipped.
+    pub fn vat(&self, region: Region) -> Vec<ReceiptVat> {
+        let parse_amount = |value: &str| value.trim().replace(',', ".").parse::<f64>().ok();
+
+        self.receipt_ui_items
+            .iter()
+            .filter_map(|item| match item {
+                ReceiptUiItem::Vat {
+                    left,
+                    center,
+                    right,
+                } => Some(ReceiptVat {
+                    rate: region.vat_rate(left)?,
+                    base: parse_amount(center)?,
+                    amount: parse_amount(right)?,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DUTCH_RECEIPT: &str = r#"{
+        "receiptUiItems": [
+            { "type": "ah-logo", "style": "default" },
+            { "type": "products-header" },
+            { "type": "product", "amount": "1,29", "description": "AH HALFVOLLE MELK", "indicator": null, "price": null, "quantity": "1" },
+            { "type": "product", "amount": "4,99", "description": "AH WASMIDDEL", "indicator": null, "price": null, "quantity": "1" },
+            { "type": "total", "label": "TOTAAL", "price": "6,28" },
+            { "type": "vat", "left": "BTW", "center": "OVER", "right": "EUR" },
+            { "type": "vat", "left": "9%", "center": "1,18", "right": "0,11" },
+            { "type": "vat", "left": "21%", "center": "4,12", "right": "0,87" }
+        ],
+        "storeId": 1001,
+        "transactionId": "AH1001-1-123",
+        "transactionMoment": "2026-10-01T09:30:00Z"
+    }"#;
+
+    // Hand-written, as no Belgian receipt was available: the Dutch layout that `ReceiptUiItem` models, with
+    // the register's VAT codes (see `Region::vat_rate`) in the VAT column and an item type that is not modelled.
+    const BELGIAN_RECEIPT: &str = r#"{
+        "receiptUiItems": [
+            { "type": "ah-logo", "style": "default" },
+            { "type": "products-header" },
+            { "type": "product", "amount": "1,89", "description": "AH HALFVOLLE MELK", "indicator": "C", "price": null, "quantity": "1" },
+            { "type": "product", "amount": "3,49", "description": "AH WASMIDDEL", "indicator": "A", "price": null, "quantity": "1" },
+            { "type": "loyalty-card", "cardNumber": "2620000000000", "label": "Bonuskaart" },
+            { "type": "total", "label": "TOTAAL", "price": "5,38" },
+            { "type": "vat", "left": "BTW", "center": "OVER", "right": "BEDRAG" },
+            { "type": "vat", "left": "A", "center": "2,88", "right": "0,61" },
+            { "type": "vat", "left": "C", "center": "1,78", "right": "0,11" }
+        ],
+        "storeId": 5001,
+        "transactionId": "AH5001-1-123",
+        "transactionMoment": "2026-10-01T09:30:00Z"
+    }"#;
+
+    #[test]
+    fn parse_dutch_vat() {
+        let receipt: Receipt = serde_json::from_str(DUTCH_RECEIPT).unwrap();
+
+        assert_eq!(
+            receipt.vat(Region::Netherlands),
+            vec![
+                ReceiptVat {
+                    rate: 9.0,
+                    base: 1.18,
+                    amount: 0.11,
+                },
+                ReceiptVat {
+                    rate: 21.0,
+                    base: 4.12,
+                    amount: 0.87,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_belgian_vat() {
+        let receipt: Receipt = serde_json::from_str(BELGIAN_RECEIPT).unwrap();
+
+        assert!(matches!(
+            receipt.receipt_ui_items[4],
+            ReceiptUiItem::Unknown
+        ));
+        assert_eq!(
+            receipt.vat(Region::Belgium),
+            vec![
+                ReceiptVat {
+                    rate: 21.0,
+                    base: 2.88,
+                    amount: 0.61,
+                },
+                ReceiptVat {
+                    rate: 6.0,
+                    base: 1.78,
+                    amount: 0.11,
+                },
+            ]
+        );
+        assert!(receipt.vat(Region::Netherlands).is_empty());
+    }
+}
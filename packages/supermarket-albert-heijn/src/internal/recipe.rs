@@ -0,0 +1,63 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+use crate::internal::scalar::*;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/search_recipes.graphql",
+    response_derives = "Debug"
+)]
+pub struct SearchRecipes;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_recipe.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetRecipe;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_recipe_products.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetRecipeProducts;
+
+impl Identifier for search_recipes::SearchRecipesRecipeSearchV2Result {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Identifier for get_recipe::GetRecipeRecipe {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum RecipeSort {
+    Newest,
+    Popular,
+    Ratings,
+    Relevance,
+    TotalTime,
+    Trending,
+}
+
+impl From<RecipeSort> for search_recipes::RecipeSearchSortOption {
+    fn from(sort: RecipeSort) -> Self {
+        match sort {
+            RecipeSort::Newest => search_recipes::RecipeSearchSortOption::NEWEST,
+            RecipeSort::Popular => search_recipes::RecipeSearchSortOption::POPULAR,
+            RecipeSort::Ratings => search_recipes::RecipeSearchSortOption::RATINGS,
+            RecipeSort::Relevance => search_recipes::RecipeSearchSortOption::MOST_RELEVANT,
+            RecipeSort::TotalTime => search_recipes::RecipeSearchSortOption::TOTAL_TIME,
+            RecipeSort::Trending => search_recipes::RecipeSearchSortOption::TRENDING,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Region {
+    Belgium,
+    #[default]
+    Netherlands,
+}
+
+impl Region {
+    pub fn api_url(&self) -> &'static str {
+        match self {
+            Region::Belgium => "https://api.ah.be",
+            Region::Netherlands => "https://api.ah.nl",
+        }
+    }
+
+    pub fn graphql_api_url(&self) -> &'static str {
+        match self {
+            Region::Belgium => "https://api.ah.be/graphql",
+            Region::Netherlands => "https://api.ah.nl/graphql",
+        }
+    }
+
+    pub fn locale(&self) -> &'static str {
+        match self {
+            Region::Belgium => "nl_BE",
+            Region::Netherlands => "nl_NL",
+        }
+    }
+
+    /// Resolves the VAT column of a receipt to a percentage.
+    ///
+    /// Dutch receipts print the percentage, Belgian receipts print the VAT code of the register (A-D).
+    /// These codes are fixed for certified cash registers (GKS) by the Belgian Royal Decree of
+    /// 30 December 2009: A is 21%, B is 12%, C is 6% and D is 0%.
+    pub fn vat_rate(&self, value: &str) -> Option<f64> {
+        let value = value.trim();
+
+        if *self == Region::Belgium {
+            match value {
+                "A" => return Some(21.0),
+                "B" => return Some(12.0),
+                "C" => return Some(6.0),
+                "D" => return Some(0.0),
+                _ => {}
+            }
+        }
+
+        value
+            .strip_suffix('%')
+            .and_then(|rate| rate.trim().replace(',', ".").parse().ok())
+    }
+}
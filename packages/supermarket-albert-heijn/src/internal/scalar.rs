@@ -1 +1,8 @@
+pub type Date = String;
+pub type DateTime = String;
 pub type EmailAddress = String;
+pub type PageSize = i64;
+pub type PhoneNumber = String;
+pub type PostalCode = String;
+#[allow(clippy::upper_case_acronyms)]
+pub type UUID = String;
@@ -0,0 +1,316 @@
+use graphql_client::GraphQLQuery;
+use serde::{Serialize, Serializer};
+use supermarket::Identifier;
+
+use crate::internal::product::{Filter, FilterOption};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/search_products.graphql",
+    response_derives = "Debug"
+)]
+pub struct SearchProducts;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/search_previously_bought.graphql",
+    response_derives = "Debug"
+)]
+pub struct SearchPreviouslyBought;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProductSearchSort {
+    NutriScore,
+    PriceHigh,
+    PriceLow,
+    PurchaseFrequency,
+    Relevance,
+    Taxonomy,
+    /// Any other value listed in `ProductSearch::sort_on`.
+    Other(String),
+}
+
+impl ProductSearchSort {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ProductSearchSort::NutriScore => "NUTRISCORE",
+            ProductSearchSort::PriceHigh => "PRICEHIGH",
+            ProductSearchSort::PriceLow => "PRICELOW",
+            ProductSearchSort::PurchaseFrequency => "PURCHASE_FREQUENCY",
+            ProductSearchSort::Relevance => "RELEVANCE",
+            ProductSearchSort::Taxonomy => "TAXONOMY",
+            ProductSearchSort::Other(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ProductSearchSort {
+    fn from(value: &str) -> Self {
+        match value {
+            "NUTRISCORE" => ProductSearchSort::NutriScore,
+            "PRICEHIGH" => ProductSearchSort::PriceHigh,
+            "PRICELOW" => ProductSearchSort::PriceLow,
+            "PURCHASE_FREQUENCY" => ProductSearchSort::PurchaseFrequency,
+            "RELEVANCE" => ProductSearchSort::Relevance,
+            "TAXONOMY" => ProductSearchSort::Taxonomy,
+            _ => ProductSearchSort::Other(value.to_string()),
+        }
+    }
+}
+
+/// Query parameters for `/mobile-services/product/search/v2`.
+///
+/// Filters returned by a previous search can be applied again with [`AlbertHeijnSearchQuery::filter`],
+/// which uses the filter id as parameter name and the option id as value.
+#[derive(Clone, Debug, Default)]
+pub struct AlbertHeijnSearchQuery {
+    query: Option<String>,
+    taxonomy_id: Option<String>,
+    brands: Vec<String>,
+    bonus: bool,
+    properties: Vec<String>,
+    filters: Vec<(String, String)>,
+    sort: Option<ProductSearchSort>,
+    page: Option<u64>,
+    size: Option<u64>,
+}
+
+impl AlbertHeijnSearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn taxonomy(mut self, taxonomy_id: &str) -> Self {
+        self.taxonomy_id = Some(taxonomy_id.to_string());
+        self
+    }
+
+    pub fn brand(mut self, brand: &str) -> Self {
+        self.brands.push(brand.to_string());
+        self
+    }
+
+    pub fn bonus(mut self, bonus: bool) -> Self {
+        self.bonus = bonus;
+        self
+    }
+
+    pub fn property(mut self, property: &str) -> Self {
+        self.properties.push(property.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: &Filter, option: &FilterOption) -> Self {
+        self.filters.push((filter.id.clone(), option.id.clone()));
+        self
+    }
+
+    pub fn sort(mut self, sort: ProductSearchSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    fn pairs(&self) -> Vec<(&str, String)> {
+        let mut pairs = vec![];
+
+        if let Some(query) = &self.query {
+            pairs.push(("query", query.clone()));
+        }
+        if let Some(taxonomy_id) = &self.taxonomy_id {
+            pairs.push(("taxonomyId", taxonomy_id.clone()));
+        }
+        for brand in &self.brands {
+            pairs.push(("brand", brand.clone()));
+        }
+        if self.bonus {
+            pairs.push(("bonus", String::from("Bonus")));
+        }
+        for property in &self.properties {
+            pairs.push(("properties", property.clone()));
+        }
+        for (id, option) in &self.filters {
+            pairs.push((id, option.clone()));
+        }
+        if let Some(sort) = &self.sort {
+            pairs.push(("sortOn", sort.as_str().to_string()));
+        }
+        if let Some(page) = self.page {
+            pairs.push(("page", page.to_string()));
+        }
+        if let Some(size) = self.size {
+            pairs.push(("size", size.to_string()));
+        }
+
+        pairs
+    }
+}
+
+impl Serialize for AlbertHeijnSearchQuery {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.pairs())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum SearchProductsSort {
+    NutriScore,
+    PriceHighLow,
+    PriceLowHigh,
+    Relevance,
+}
+
+impl From<SearchProductsSort> for search_products::SearchProductsSortType {
+    fn from(sort: SearchProductsSort) -> Self {
+        match sort {
+            SearchProductsSort::NutriScore => {
+                search_products::SearchProductsSortType::NUTRI_SCORE_A_E
+            }
+            SearchProductsSort::PriceHighLow => {
+                search_products::SearchProductsSortType::PRICE_HIGH_LOW
+            }
+            SearchProductsSort::PriceLowHigh => {
+                search_products::SearchProductsSortType::PRICE_LOW_HIGH
+            }
+            SearchProductsSort::Relevance => search_products::SearchProductsSortType::RELEVANCE,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum PreviouslyBoughtSort {
+    NutriScore,
+    PurchaseDate,
+    PurchaseFrequency,
+    PurchaseTaxonomy,
+}
+
+impl From<PreviouslyBoughtSort> for search_previously_bought::SearchPreviouslyBoughtSortType {
+    fn from(sort: PreviouslyBoughtSort) -> Self {
+        match sort {
+            PreviouslyBoughtSort::NutriScore => {
+                search_previously_bought::SearchPreviouslyBoughtSortType::NUTRI_SCORE_A_E
+            }
+            PreviouslyBoughtSort::PurchaseDate => {
+                search_previously_bought::SearchPreviouslyBoughtSortType::PURCHASE_DATE
+            }
+            PreviouslyBoughtSort::PurchaseFrequency => {
+                search_previously_bought::SearchPreviouslyBoughtSortType::PURCHASE_FREQUENCY
+            }
+            PreviouslyBoughtSort::PurchaseTaxonomy => {
+                search_previously_bought::SearchPreviouslyBoughtSortType::PURCHASE_TAXONOMY
+            }
+        }
+    }
+}
+
+/// Product as returned by the GraphQL searches, which all select the same fields.
+#[derive(Debug)]
+pub struct SearchProduct {
+    pub id: i64,
+    pub title: Option<String>,
+    pub brand: Option<String>,
+    pub category: Option<String>,
+    pub sales_unit_size: Option<String>,
+    pub price: Option<f64>,
+    pub was_price: Option<f64>,
+    pub orderable: bool,
+}
+
+impl Identifier for SearchProduct {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchResult {
+    pub total_found: i64,
+    pub products: Vec<SearchProduct>,
+}
+
+/// Both searches select the same product fields, but `graphql_client` generates distinct types per query.
+macro_rules! impl_from_search_result {
+    ($result:ty) => {
+        impl From<$result> for SearchResult {
+            fn from(result: $result) -> Self {
+                SearchResult {
+                    total_found: result.total_found,
+                    products: result
+                        .products
+                        .into_iter()
+                        .map(|product| SearchProduct {
+                            id: product.id,
+                            title: product.title,
+                            brand: product.brand,
+                            category: product.category,
+                            sales_unit_size: product.sales_unit_size,
+                            price: product.price.as_ref().map(|price| price.now.amount),
+                            was_price: product
+                                .price
+                                .and_then(|price| price.was)
+                                .map(|was| was.amount),
+                            orderable: product
+                                .availability
+                                .is_some_and(|availability| availability.is_orderable),
+                        })
+                        .collect(),
+                }
+            }
+        }
+    };
+}
+
+impl_from_search_result!(search_products::SearchProductsSearchProducts);
+impl_from_search_result!(search_previously_bought::SearchPreviouslyBoughtSearchPreviouslyBought);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_search_query() {
+        let option = FilterOption {
+            count: 12,
+            display: true,
+            id: String::from("a"),
+            label: String::from("A"),
+        };
+        let filter = Filter {
+            boolean_filter: false,
+            id: String::from("nutriscore"),
+            label: String::from("Nutri-Score"),
+            options: vec![],
+            r#type: String::from("CHECKBOX"),
+        };
+
+        let query = AlbertHeijnSearchQuery::new()
+            .query("halfvolle melk")
+            .brand("AH")
+            .bonus(true)
+            .filter(&filter, &option)
+            .sort(ProductSearchSort::PriceLow)
+            .page(2);
+
+        assert_eq!(
+            serde_urlencoded::to_string(&query).unwrap(),
+            "query=halfvolle+melk&brand=AH&bonus=Bonus&nutriscore=a&sortOn=PRICELOW&page=2"
+        );
+    }
+}
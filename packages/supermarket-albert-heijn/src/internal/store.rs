@@ -0,0 +1,120 @@
+use graphql_client::GraphQLQuery;
+use supermarket::Identifier;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/search_stores.graphql",
+    response_derives = "Debug"
+)]
+pub struct SearchStores;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_store.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetStore;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_favourite_store.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetFavouriteStore;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/set_favourite_store.graphql",
+    response_derives = "Debug"
+)]
+pub struct SetFavouriteStore;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_store_location_suggestions.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetStoreLocationSuggestions;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_store_stocks.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetStoreStocks;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_store_stock_alternative_products.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetStoreStockAlternativeProducts;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/get_store_stock_alternative_stores.graphql",
+    response_derives = "Debug"
+)]
+pub struct GetStoreStockAlternativeStores;
+
+impl Identifier for search_stores::SearchStoresStoresSearchResult {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Identifier for get_store::GetStoreStoresInformation {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+impl Identifier for get_favourite_store::GetFavouriteStoreStoresFavouriteStore {
+    fn identifier(&self) -> String {
+        self.id.to_string()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum StoreSearch {
+    City(String),
+    PostalCode(String),
+    Location { latitude: f64, longitude: f64 },
+}
+
+impl From<&StoreSearch> for search_stores::StoresFilterInput {
+    fn from(search: &StoreSearch) -> Self {
+        let mut filter = search_stores::StoresFilterInput {
+            city_starts_with: None,
+            location: None,
+            opening_hours: None,
+            postal_code: None,
+            services: None,
+            store_type: None,
+        };
+
+        match search {
+            StoreSearch::City(city) => filter.city_starts_with = Some(city.clone()),
+            StoreSearch::PostalCode(postal_code) => filter.postal_code = Some(postal_code.clone()),
+            StoreSearch::Location {
+                latitude,
+                longitude,
+            } => {
+                filter.location = Some(search_stores::GeoLocationInput {
+                    latitude: *latitude,
+                    longitude: *longitude,
+                })
+            }
+        }
+
+        filter
+    }
+}
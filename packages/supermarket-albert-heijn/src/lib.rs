@@ -1,12 +1,15 @@
 mod client;
 mod internal;
 
-pub use client::AlbertHeijnClient;
+pub use client::{AlbertHeijnClient, PROVIDER};
+pub use internal::{
+    AlbertHeijnSearchQuery, AlbertHeijnToken, BasketItem, FavoriteListProduct, MilesTransaction,
+    OrderAddress, PreviouslyBoughtSort, ProductSearchSort, PurchaseHistorySort, RecipeSort, Region,
+    SearchProduct, SearchProductsSort, SearchResult, StoreSearch, link_miles_transactions,
+};
 
 #[cfg(test)]
 mod tests {
-    use self::internal::AlbertHeijnToken;
-
     use super::*;
     use supermarket::{Identifier, credentials::Credentials, internal::GraphQLClientError};
 
@@ -17,7 +20,7 @@ mod tests {
         let client = AlbertHeijnClient::new();
 
         if let Some(token) = credentials.get::<AlbertHeijnToken>("albert_heijn") {
-            client.set_token(token).await
+            client.set_token(token).await?;
         }
 
         let token = client.token().await;
@@ -43,7 +46,7 @@ mod tests {
 
         // let product_search = client
         //     .internal
-        //     .search_products([["bonus", "Bonus"]])
+        //     .search_products(&AlbertHeijnSearchQuery::new().bonus(true))
         //     .await?;
         // println!("{:#?}", product_search);
 
@@ -1,29 +1,51 @@
+import { useState } from 'react';
+
 import { Button } from '~/components/ui/button';
-import { SUPERMARKETS } from '~/constants/supermarkets';
+import { DEFAULT_ACCOUNT, SUPERMARKETS } from '~/constants/supermarkets';
 
 export const App = () => {
     const startUrl = `${window.location.origin}${window.location.pathname}`;
     const params = new URLSearchParams(window.location.search);
     const supermarketId = params.get('supermarket-id');
-    const supermarketCode = params.get('supermarket-code');
+    const messagePath = params.get('message-path');
     const supermarket = SUPERMARKETS.find((supermarket) => supermarket.id === supermarketId);
 
+    const [account, setAccount] = useState(params.get('account') || DEFAULT_ACCOUNT);
+
+    // The main process reads the account from this page's URL when an authorization starts.
+    const changeAccount = (value: string) => {
+        setAccount(value);
+
+        const url = new URL(window.location.href);
+        url.searchParams.set('account', value);
+        window.history.replaceState(null, '', url);
+    };
+
     return (
         <div className="flex justify-center">
             <div className="flex max-w-xl flex-col items-center gap-4 p-8">
                 <h1 className="text-xl font-bold">Supermarket Authenticator</h1>
 
-                {supermarket && supermarketCode ? (
+                {supermarket && messagePath ? (
                     <div className="flex flex-col items-center gap-4">
-                        <h2 className="font-bold">{supermarket.name}</h2>
-                        <code className="bg-muted rounded-md px-4 py-2 font-mono">{supermarketCode}</code>
+                        <h2 className="font-bold">{`${supermarket.name} (${account})`}</h2>
+                        <p>Authorization saved to</p>
+                        <code className="bg-muted rounded-md px-4 py-2 font-mono text-sm">{messagePath}</code>
 
                         <Button asChild>
-                            <a href={startUrl}>Back to start</a>
+                            <a href={`${startUrl}?${new URLSearchParams({ account }).toString()}`}>Back to start</a>
                         </Button>
                     </div>
                 ) : (
                     <div className="flex w-full flex-col gap-2">
+                        <label htmlFor="account" className="text-sm font-medium">Account</label>
+                        <input
+                            id="account"
+                            className="border-input h-9 rounded-md border px-3 text-sm"
+                            value={account}
+                            onChange={(event) => changeAccount(event.target.value)}
+                        />
+
                         {SUPERMARKETS.map((supermarket) => (
                             <Button key={supermarket.id} className="flex-grow" asChild>
                                 <a href={supermarket.authorizationUrl}>{supermarket.name}</a>
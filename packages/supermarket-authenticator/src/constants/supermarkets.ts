@@ -4,6 +4,8 @@ export type Supermarket = {
     authorizationUrl: string;
     redirectUrlPrefix: string;
     codeParam: string;
+    /** Whether the authorization URL needs a PKCE challenge, so the code verifier has to be captured. */
+    pkce: boolean;
 };
 
 export const SUPERMARKETS: Supermarket[] = [
@@ -14,16 +16,33 @@ export const SUPERMARKETS: Supermarket[] = [
             'https://login.ah.nl/login?response_type=code&client_id=appie-android&redirect_uri=appie://login-exit',
         redirectUrlPrefix: 'appie://login-exit',
         codeParam: 'code',
+        pkce: false,
     },
     {
         id: 'jumbo',
         name: 'Jumbo',
-        authorizationUrl: 'https://loyalty-app.jumbo.com',
+        authorizationUrl:
+            'https://auth.jumbo.com/authorize?response_type=code&client_id=ZVa0cW0LadbDHINgrBLuEAp5amVBKQh1&redirect_uri=https%3A%2F%2Floyalty-app.jumbo.com%2Fhome&scope=openid%20offline_access',
         redirectUrlPrefix: 'https://loyalty-app.jumbo.com/home',
         codeParam: 'code',
+        pkce: true,
     },
 ];
 
+export const getSupermarketFromAuthorizationUrl = (url: string): Supermarket | undefined => {
+    const parsedUrl = new URL(url);
+
+    return SUPERMARKETS.find((supermarket) => {
+        const authorizationUrl = new URL(supermarket.authorizationUrl);
+
+        return (
+            parsedUrl.origin === authorizationUrl.origin &&
+            parsedUrl.pathname === authorizationUrl.pathname &&
+            parsedUrl.searchParams.get('client_id') === authorizationUrl.searchParams.get('client_id')
+        );
+    });
+};
+
 export const getCodeFromRedirectUrl = (redirectUrl: string): [Supermarket, string] | undefined => {
     for (const supermarket of SUPERMARKETS) {
         if (redirectUrl.startsWith(supermarket.redirectUrlPrefix)) {
@@ -40,3 +59,24 @@ export const getCodeFromRedirectUrl = (redirectUrl: string): [Supermarket, strin
 
     return undefined;
 };
+
+export const AUTHENTICATOR_MESSAGE_VERSION = 1;
+
+export const DEFAULT_ACCOUNT = 'default';
+
+export const createAuthenticatorMessage = (
+    supermarket: Supermarket,
+    account: string,
+    code: string,
+    codeVerifier?: string,
+): string =>
+    JSON.stringify(
+        {
+            version: AUTHENTICATOR_MESSAGE_VERSION,
+            supermarket: supermarket.id,
+            account,
+            authorization: codeVerifier ? { type: 'code', code, codeVerifier } : { type: 'code', code },
+        },
+        null,
+        4,
+    );
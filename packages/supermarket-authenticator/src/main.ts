@@ -1,7 +1,29 @@
+import { createHash, randomBytes } from 'node:crypto';
+import { mkdir, writeFile } from 'node:fs/promises';
+import { join } from 'node:path';
+
 import { BrowserWindow, app } from 'electron';
 import started from 'electron-squirrel-startup';
 
-import { getCodeFromRedirectUrl } from './constants/supermarkets';
+import {
+    DEFAULT_ACCOUNT,
+    type Supermarket,
+    createAuthenticatorMessage,
+    getCodeFromRedirectUrl,
+    getSupermarketFromAuthorizationUrl,
+} from './constants/supermarkets';
+
+// Directory for authenticator messages, to be picked up with `SessionManager::receive_file`.
+const getMessageDir = () => process.env.SUPERMARKET_AUTHENTICATOR_DIR ?? join(app.getPath('userData'), 'messages');
+
+// Account used when none is chosen in the renderer.
+const getDefaultAccount = () => process.env.SUPERMARKET_AUTHENTICATOR_ACCOUNT || DEFAULT_ACCOUNT;
+
+// Accounts of pending authorizations, by supermarket id.
+const accounts = new Map<string, string>();
+
+// PKCE code verifiers of pending authorizations, by supermarket id.
+const codeVerifiers = new Map<string, string>();
 
 // Handle creating/removing shortcuts on Windows when installing/uninstalling.
 if (started) {
@@ -23,9 +45,14 @@ const createWindow = async () => {
         intercept(mainWindow, event);
     });
 
-    await loadRenderer(mainWindow);
+    await loadRenderer(mainWindow, { account: getDefaultAccount() });
 };
 
+const isRendererUrl = (url: string) =>
+    MAIN_WINDOW_VITE_DEV_SERVER_URL
+        ? new URL(url).origin === new URL(MAIN_WINDOW_VITE_DEV_SERVER_URL).origin
+        : new URL(url).protocol === 'file:';
+
 const loadRenderer = async (window: BrowserWindow, query: Record<string, string> = {}) => {
     if (MAIN_WINDOW_VITE_DEV_SERVER_URL) {
         const url = new URL(MAIN_WINDOW_VITE_DEV_SERVER_URL);
@@ -46,18 +73,62 @@ const intercept = (
         | Electron.Event<Electron.WebContentsWillNavigateEventParams>
         | Electron.Event<Electron.WebContentsWillRedirectEventParams>,
 ) => {
-    console.log(event);
+    const authorizationSupermarket = getSupermarketFromAuthorizationUrl(event.url);
+
+    // The account is chosen in the renderer, so only a navigation from there starts a new authorization.
+    const currentUrl = window.webContents.getURL();
+    if (authorizationSupermarket && isRendererUrl(currentUrl)) {
+        const account = new URL(currentUrl).searchParams.get('account') || getDefaultAccount();
+        accounts.set(authorizationSupermarket.id, account);
+    }
+
+    if (authorizationSupermarket?.pkce && !new URL(event.url).searchParams.has('code_challenge')) {
+        const codeVerifier = randomBytes(32).toString('base64url');
+        const codeChallenge = createHash('sha256').update(codeVerifier).digest('base64url');
+        codeVerifiers.set(authorizationSupermarket.id, codeVerifier);
+
+        const url = new URL(event.url);
+        url.searchParams.set('code_challenge', codeChallenge);
+        url.searchParams.set('code_challenge_method', 'S256');
+
+        event.preventDefault();
+        void window.loadURL(url.toString());
+        return;
+    }
 
     const result = getCodeFromRedirectUrl(event.url);
     if (result) {
         const [supermarket, code] = result;
-        console.log(supermarket, code);
 
         event.preventDefault();
-        void loadRenderer(window, { 'supermarket-id': supermarket.id, 'supermarket-code': code });
+        void receiveCode(window, supermarket, code);
     }
 };
 
+const receiveCode = async (window: BrowserWindow, supermarket: Supermarket, code: string) => {
+    const account = accounts.get(supermarket.id) ?? getDefaultAccount();
+    accounts.delete(supermarket.id);
+    const codeVerifier = codeVerifiers.get(supermarket.id);
+    codeVerifiers.delete(supermarket.id);
+
+    const path = await writeMessage(
+        supermarket.id,
+        createAuthenticatorMessage(supermarket, account, code, codeVerifier),
+    );
+    await loadRenderer(window, { 'supermarket-id': supermarket.id, 'message-path': path, account });
+};
+
+const writeMessage = async (supermarketId: string, message: string): Promise<string> => {
+    const messageDir = getMessageDir();
+    await mkdir(messageDir, { recursive: true });
+
+    // The message contains an authorization code, so only the owner may read it.
+    const path = join(messageDir, `${supermarketId}-${Date.now()}.json`);
+    await writeFile(path, message, { mode: 0o600 });
+
+    return path;
+};
+
 app.on('ready', () => {
     void createWindow();
 });
@@ -1,11 +1,18 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use supermarket::{
-    Client, ClientError, Identifier,
+    AuthState, Authorization, Client, ClientError, Identifier, Secret,
+    profile::Profile,
     receipt::{Receipt, ReceiptSummary},
+    token::TokenStore,
 };
 
 use crate::internal::{JumboInternalClient, JumboToken};
 
+/// Stable provider id, persisted in account names and matching the authenticator's supermarket id.
+pub const PROVIDER: &str = "jumbo";
+
 pub struct JumboClient {
     pub internal: JumboInternalClient,
 }
@@ -17,11 +24,28 @@ impl JumboClient {
         }
     }
 
-    pub async fn auth_with_code(&self, code: &str, code_verifier: &str) -> Result<(), ClientError> {
+    pub fn with_token_store(token_store: Arc<dyn TokenStore<JumboToken>>) -> Self {
+        JumboClient {
+            internal: JumboInternalClient::with_token_store(token_store),
+        }
+    }
+
+    pub async fn auth_with_code(
+        &self,
+        code: Secret<String>,
+        code_verifier: Secret<String>,
+    ) -> Result<(), ClientError> {
         self.internal.auth_with_code(code, code_verifier).await
     }
 
-    pub async fn auth_with_refresh_token(&self, refresh_token: &str) -> Result<(), ClientError> {
+    pub async fn logout(&self) -> Result<(), ClientError> {
+        self.internal.logout().await
+    }
+
+    pub async fn auth_with_refresh_token(
+        &self,
+        refresh_token: Secret<String>,
+    ) -> Result<(), ClientError> {
         self.internal.auth_with_refresh_token(refresh_token).await
     }
 
@@ -29,13 +53,45 @@ impl JumboClient {
         self.internal.token().await
     }
 
-    pub async fn set_token(&self, token: JumboToken) {
+    pub async fn set_token(&self, token: JumboToken) -> Result<(), ClientError> {
         self.internal.set_token(token).await
     }
 }
 
 #[async_trait]
 impl Client for JumboClient {
+    fn provider(&self) -> &'static str {
+        PROVIDER
+    }
+
+    async fn auth_state(&self) -> AuthState {
+        self.internal.auth_state().await
+    }
+
+    async fn authorize(&self, authorization: Authorization) -> Result<(), ClientError> {
+        match authorization {
+            Authorization::Code {
+                code,
+                code_verifier: Some(code_verifier),
+            } => self.auth_with_code(code, code_verifier).await,
+            Authorization::Code {
+                code_verifier: None,
+                ..
+            } => Err(ClientError::TextError(String::from("No code verifier"))),
+            Authorization::RefreshToken { refresh_token } => {
+                self.auth_with_refresh_token(refresh_token).await
+            }
+        }
+    }
+
+    async fn profile(&self) -> Result<Profile, ClientError> {
+        self.internal
+            .profile()
+            .await?
+            .map(Profile::from)
+            .ok_or(ClientError::TextError(String::from("No profile")))
+    }
+
     async fn receipts(&self) -> Result<Vec<ReceiptSummary>, ClientError> {
         Ok(self
             .internal
@@ -54,6 +110,7 @@ impl Client for JumboClient {
             id: r.identifier(),
             created_at: r.purchase_end_on,
             products: vec![],
+            vat: vec![],
         })
     }
 }
@@ -1,43 +1,61 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local, TimeDelta};
-use reqwest::RequestBuilder;
+use reqwest::{Method, RequestBuilder, StatusCode};
 use serde::{Deserialize, Serialize};
 use supermarket::internal::{Auth, ClientError, JsonClient};
 use supermarket::serde::Nothing;
+use supermarket::token::TokenStore;
+use supermarket::{AuthState, Secret};
 
 const OAUTH_CLIENT_ID: &str = "ZVa0cW0LadbDHINgrBLuEAp5amVBKQh1";
 const OAUTH_REDIRECT_URI: &str = "https://loyalty-app.jumbo.com/home";
 
 #[derive(Deserialize, Debug)]
 struct Token {
-    access_token: String,
+    access_token: Secret<String>,
     expires_in: i64,
     // id_token: String,
-    refresh_token: String,
+    refresh_token: Secret<String>,
     // scope: String,
     // token_type: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct JumboToken {
-    pub access_token: Option<(String, DateTime<Local>)>,
-    pub refresh_token: Option<String>,
+    pub access_token: Option<(Secret<String>, DateTime<Local>)>,
+    pub refresh_token: Option<Secret<String>>,
 }
 
 pub struct JumboAuth {
     json_client: JsonClient,
-    access_token: Option<(String, DateTime<Local>)>,
-    refresh_token: Option<String>,
+    access_token: Option<(Secret<String>, DateTime<Local>)>,
+    refresh_token: Option<Secret<String>>,
+    refresh_token_rejected: bool,
+    token_store: Option<Arc<dyn TokenStore<JumboToken>>>,
+    /// Set when the stored token could not be read, so it is not overwritten until a token is set explicitly.
+    token_store_error: Option<String>,
 }
 
 impl JumboAuth {
-    pub fn new(json_client: JsonClient) -> Self {
+    pub fn new(
+        json_client: JsonClient,
+        token_store: Option<Arc<dyn TokenStore<JumboToken>>>,
+    ) -> Self {
+        let (token, token_store_error) = match token_store.as_ref().map(|store| store.load()) {
+            Some(Ok(token)) => (token, None),
+            Some(Err(e)) => (None, Some(format!("{e:?}"))),
+            None => (None, None),
+        };
+
         JumboAuth {
             json_client,
-            access_token: None,
-            refresh_token: None,
+            access_token: token.as_ref().and_then(|token| token.access_token.clone()),
+            refresh_token: token.and_then(|token| token.refresh_token),
+            refresh_token_rejected: false,
+            token_store,
+            token_store_error,
         }
     }
 
@@ -48,12 +66,44 @@ impl JumboAuth {
         }
     }
 
-    pub fn set_token(&mut self, token: JumboToken) {
+    pub fn set_token(&mut self, token: JumboToken) -> Result<(), ClientError> {
         self.access_token = token.access_token;
         self.refresh_token = token.refresh_token;
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+        self.save_token()
     }
 
-    fn process_token(&mut self, token: Token) -> String {
+    pub fn clear_token(&mut self) -> Result<(), ClientError> {
+        self.access_token = None;
+        self.refresh_token = None;
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+
+        match &self.token_store {
+            Some(token_store) => token_store.clear(),
+            None => Ok(()),
+        }
+    }
+
+    fn save_token(&self) -> Result<(), ClientError> {
+        match &self.token_store {
+            Some(token_store) => token_store.save(&self.token()),
+            None => Ok(()),
+        }
+    }
+
+    pub fn auth_state(&self) -> AuthState {
+        if self.refresh_token_rejected {
+            AuthState::Expired
+        } else if self.access_token.is_none() && self.refresh_token.is_none() {
+            AuthState::Unauthenticated
+        } else {
+            AuthState::Authenticated
+        }
+    }
+
+    fn process_token(&mut self, token: Token) -> Result<Secret<String>, ClientError> {
         let access_token = token.access_token.clone();
 
         self.access_token = Some((
@@ -61,17 +111,18 @@ impl JumboAuth {
             Local::now() + TimeDelta::seconds(token.expires_in),
         ));
         self.refresh_token = Some(token.refresh_token);
+        self.refresh_token_rejected = false;
+        self.token_store_error = None;
+        self.save_token()?;
 
-        println!("{:#?} | {:#?}", self.access_token, self.refresh_token);
-
-        access_token
+        Ok(access_token)
     }
 
     pub async fn request_token(
         &mut self,
-        code: String,
-        code_verifier: String,
-    ) -> Result<String, ClientError> {
+        code: Secret<String>,
+        code_verifier: Secret<String>,
+    ) -> Result<Secret<String>, ClientError> {
         let token = self
             .json_client
             .post::<_, _, Token>(
@@ -79,18 +130,18 @@ impl JumboAuth {
                 Nothing,
                 HashMap::from([
                     ("client_id", OAUTH_CLIENT_ID),
-                    ("code", &code),
-                    ("code_verifier", &code_verifier),
+                    ("code", code.expose()),
+                    ("code_verifier", code_verifier.expose()),
                     ("grant_type", "authorization_code"),
                     ("redirect_uri", OAUTH_REDIRECT_URI),
                 ]),
             )
             .await?;
 
-        Ok(self.process_token(token))
+        self.process_token(token)
     }
 
-    pub async fn refresh_token(&mut self) -> Result<String, ClientError> {
+    pub async fn refresh_token(&mut self) -> Result<Secret<String>, ClientError> {
         if let Some(refresh_token) = &self.refresh_token {
             let token = self
                 .json_client
@@ -100,33 +151,72 @@ impl JumboAuth {
                     HashMap::from([
                         ("client_id", OAUTH_CLIENT_ID),
                         ("grant_type", "refresh_token"),
-                        ("refresh_token", refresh_token),
+                        ("refresh_token", refresh_token.expose()),
                     ]),
                 )
-                .await?;
-
-            Ok(self.process_token(token))
+                .await
+                .inspect_err(|e| {
+                    // Only an explicit rejection (e.g. `invalid_grant`) expires the session, not outages or rate limits
+                    self.refresh_token_rejected = matches!(
+                        e,
+                        ClientError::StatusError(
+                            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED,
+                            _
+                        )
+                    )
+                })?;
+
+            self.process_token(token)
         } else {
             Err(ClientError::TextError(String::from("No refresh token")))
         }
     }
+
+    pub async fn revoke_token(&mut self) -> Result<(), ClientError> {
+        let result = match &self.refresh_token {
+            Some(refresh_token) => self
+                .json_client
+                .send(
+                    Method::POST,
+                    "/oauth/revoke",
+                    Nothing,
+                    HashMap::from([
+                        ("client_id", OAUTH_CLIENT_ID),
+                        ("token", refresh_token.expose()),
+                    ]),
+                )
+                .await
+                .map(|_| ()),
+            None => Ok(()),
+        };
+
+        self.clear_token()?;
+
+        result
+    }
 }
 
 #[async_trait]
 impl Auth for JumboAuth {
     async fn request(&mut self, builder: RequestBuilder) -> Result<RequestBuilder, ClientError> {
+        if let Some(e) = &self.token_store_error {
+            return Err(ClientError::TextError(format!(
+                "Unreadable token store: {e}"
+            )));
+        }
+
         if let Some((access_token, expires_at)) = &self.access_token {
             if *expires_at > Local::now() {
-                Ok(builder.bearer_auth(access_token))
+                Ok(builder.bearer_auth(access_token.expose()))
             } else {
                 let access_token = self.refresh_token().await?;
 
-                Ok(builder.bearer_auth(access_token))
+                Ok(builder.bearer_auth(access_token.expose()))
             }
         } else if self.refresh_token.is_some() {
             let access_token = self.refresh_token().await?;
 
-            Ok(builder.bearer_auth(access_token))
+            Ok(builder.bearer_auth(access_token.expose()))
         } else {
             Ok(builder)
         }
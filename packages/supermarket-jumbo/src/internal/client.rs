@@ -6,6 +6,8 @@ use supermarket::internal::{
     Auth, ClientError, GraphQLClient, GraphQLClientError, JsonClient, NoAuth,
 };
 use supermarket::serde::Nothing;
+use supermarket::token::TokenStore;
+use supermarket::{AuthState, Secret};
 use tokio::sync::Mutex;
 
 use crate::internal::auth::{JumboAuth, JumboToken};
@@ -69,12 +71,23 @@ pub struct JumboInternalClient {
 
 impl JumboInternalClient {
     pub fn new() -> Self {
+        Self::build(None)
+    }
+
+    pub fn with_token_store(token_store: Arc<dyn TokenStore<JumboToken>>) -> Self {
+        Self::build(Some(token_store))
+    }
+
+    fn build(token_store: Option<Arc<dyn TokenStore<JumboToken>>>) -> Self {
         let no_auth = Arc::new(Mutex::new(NoAuth::new()));
-        let auth = Arc::new(Mutex::new(JumboAuth::new(JsonClient::new(
-            new_auth_api_client(),
-            AUTH_API_URL,
-            Arc::new(Mutex::new(NoAuth::new())),
-        ))));
+        let auth = Arc::new(Mutex::new(JumboAuth::new(
+            JsonClient::new(
+                new_auth_api_client(),
+                AUTH_API_URL,
+                Arc::new(Mutex::new(NoAuth::new())),
+            ),
+            token_store,
+        )));
 
         JumboInternalClient {
             auth: Arc::clone(&auth),
@@ -105,24 +118,38 @@ impl JumboInternalClient {
         self.auth.lock().await.token()
     }
 
-    pub async fn set_token(&self, token: JumboToken) {
+    pub async fn auth_state(&self) -> AuthState {
+        self.auth.lock().await.auth_state()
+    }
+
+    pub async fn set_token(&self, token: JumboToken) -> Result<(), ClientError> {
         self.auth.lock().await.set_token(token)
     }
 
-    pub async fn auth_with_code(&self, code: &str, code_verifier: &str) -> Result<(), ClientError> {
+    pub async fn auth_with_code(
+        &self,
+        code: Secret<String>,
+        code_verifier: Secret<String>,
+    ) -> Result<(), ClientError> {
         let mut auth = self.auth.lock().await;
-        auth.request_token(code.to_string(), code_verifier.to_string())
-            .await?;
+        auth.request_token(code, code_verifier).await?;
 
         Ok(())
     }
 
-    pub async fn auth_with_refresh_token(&self, refresh_token: &str) -> Result<(), ClientError> {
+    pub async fn logout(&self) -> Result<(), ClientError> {
+        self.auth.lock().await.revoke_token().await
+    }
+
+    pub async fn auth_with_refresh_token(
+        &self,
+        refresh_token: Secret<String>,
+    ) -> Result<(), ClientError> {
         let mut auth = self.auth.lock().await;
         auth.set_token(JumboToken {
             access_token: None,
-            refresh_token: Some(refresh_token.to_string()),
-        });
+            refresh_token: Some(refresh_token),
+        })?;
         auth.refresh_token().await?;
 
         Ok(())
@@ -1,5 +1,6 @@
 use graphql_client::GraphQLQuery;
 use supermarket::Identifier;
+use supermarket::profile::{Profile, ProfileAddress, ProfileCard};
 
 use self::get_profile::*;
 
@@ -16,3 +17,36 @@ impl Identifier for GetProfileProfile {
         self.customer_id.to_string()
     }
 }
+
+impl From<GetProfileProfile> for Profile {
+    fn from(profile: GetProfileProfile) -> Self {
+        let last_name = [profile.name.middle_name, profile.name.family_name]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Profile {
+            id: profile.customer_id,
+            email_address: Some(profile.email),
+            first_name: profile.name.given_name,
+            last_name: (!last_name.is_empty()).then_some(last_name),
+            phone_numbers: vec![],
+            addresses: vec![ProfileAddress {
+                street: Some(profile.home_address.street),
+                house_number: Some(format!(
+                    "{}{}",
+                    profile.home_address.number, profile.home_address.apartment
+                )),
+                postal_code: Some(profile.home_address.postal_code),
+                city: Some(profile.home_address.city),
+                country_code: Some(profile.home_address.country.code),
+            }],
+            cards: vec![ProfileCard {
+                r#type: String::from("loyalty"),
+                number: profile.loyalty_card.number,
+            }],
+            preferences: vec![],
+        }
+    }
+}
@@ -1,12 +1,11 @@
 mod client;
 mod internal;
 
-pub use client::JumboClient;
+pub use client::{JumboClient, PROVIDER};
+pub use internal::JumboToken;
 
 #[cfg(test)]
 mod tests {
-    use self::internal::JumboToken;
-
     use super::*;
     use supermarket::{credentials::Credentials, internal::GraphQLClientError};
 
@@ -17,7 +16,7 @@ mod tests {
         let client = JumboClient::new();
 
         if let Some(token) = credentials.get::<JumboToken>("jumbo") {
-            client.set_token(token).await
+            client.set_token(token).await?;
         }
 
         let profile = client.internal.profile().await?;
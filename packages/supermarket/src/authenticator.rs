@@ -0,0 +1,166 @@
+//! File-drop format for messages produced by `supermarket-authenticator`.
+//!
+//! Each message is a single JSON file:
+//!
+//! ```json
+//! {
+//!     "version": 1,
+//!     "supermarket": "albert-heijn",
+//!     "account": "default",
+//!     "authorization": { "type": "code", "code": "..." }
+//! }
+//! ```
+//!
+//! `authorization` is either `{ "type": "code", "code": "...", "codeVerifier": "..." }` or
+//! `{ "type": "refreshToken", "refreshToken": "..." }`. `account` is optional and defaults to
+//! [`DEFAULT_ACCOUNT`].
+//!
+//! The authenticator writes each message to `$SUPERMARKET_AUTHENTICATOR_DIR`, or a `messages` directory in
+//! its user data directory, for [`SessionManager::receive_file`] to pick up. `codeVerifier` is only present
+//! for supermarkets that use PKCE (Jumbo), where the authenticator generates the challenge itself. `account` is
+//! the account entered in the authenticator's window, which starts as `$SUPERMARKET_AUTHENTICATOR_ACCOUNT` or
+//! [`DEFAULT_ACCOUNT`].
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Authorization, ClientError,
+    session::{Account, SessionManager},
+};
+
+pub const VERSION: u32 = 1;
+pub const DEFAULT_ACCOUNT: &str = "default";
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct AuthenticatorMessage {
+    pub version: u32,
+    pub supermarket: String,
+    pub account: Option<String>,
+    pub authorization: Authorization,
+}
+
+/// Only the version, so messages of other versions are reported as such instead of as unknown fields.
+#[derive(Deserialize)]
+struct AuthenticatorMessageVersion {
+    version: u32,
+}
+
+impl AuthenticatorMessage {
+    pub fn parse(contents: &str) -> Result<Self, ClientError> {
+        let version = serde_json::from_str::<AuthenticatorMessageVersion>(contents)
+            .map_err(|e| ClientError::TextError(e.to_string()))?
+            .version;
+
+        if version != VERSION {
+            return Err(ClientError::TextError(format!(
+                "Unsupported authenticator message version {version}"
+            )));
+        }
+
+        serde_json::from_str::<AuthenticatorMessage>(contents)
+            .map_err(|e| ClientError::TextError(e.to_string()))
+    }
+
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, ClientError> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| ClientError::TextError(e.to_string()))?;
+
+        Self::parse(&contents)
+    }
+
+    pub fn account(&self) -> Account {
+        Account::new(
+            &self.supermarket,
+            self.account.as_deref().unwrap_or(DEFAULT_ACCOUNT),
+        )
+    }
+}
+
+impl SessionManager {
+    pub async fn receive(&self, message: AuthenticatorMessage) -> Result<Account, ClientError> {
+        let account = message.account();
+
+        self.authorize(&account, message.authorization).await?;
+
+        Ok(account)
+    }
+
+    /// Reads the message at `path`, authorizes the matching account and removes the file, as it contains secrets.
+    pub async fn receive_file<P: AsRef<Path>>(&self, path: P) -> Result<Account, ClientError> {
+        let account = self.receive(AuthenticatorMessage::read(&path)?).await?;
+
+        fs::remove_file(path).map_err(|e| ClientError::TextError(e.to_string()))?;
+
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_code() {
+        let message = AuthenticatorMessage::parse(
+            r#"{
+                "version": 1,
+                "supermarket": "jumbo",
+                "authorization": { "type": "code", "code": "abc", "codeVerifier": "xyz" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(message.account(), Account::new("jumbo", DEFAULT_ACCOUNT));
+        match message.authorization {
+            Authorization::Code {
+                code,
+                code_verifier,
+            } => {
+                assert_eq!(code.expose(), "abc");
+                assert_eq!(code_verifier.unwrap().expose(), "xyz");
+            }
+            authorization => panic!("Unexpected authorization {authorization:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_refresh_token() {
+        let message = AuthenticatorMessage::parse(
+            r#"{
+                "version": 1,
+                "supermarket": "albert-heijn",
+                "account": "work",
+                "authorization": { "type": "refreshToken", "refreshToken": "abc" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(message.account(), Account::new("albert-heijn", "work"));
+        match message.authorization {
+            Authorization::RefreshToken { refresh_token } => {
+                assert_eq!(refresh_token.expose(), "abc");
+            }
+            authorization => panic!("Unexpected authorization {authorization:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_unsupported_version() {
+        let result = AuthenticatorMessage::parse(
+            r#"{
+                "version": 2,
+                "supermarket": "albert-heijn",
+                "account": "default",
+                "createdAt": "2026-10-19T12:00:00Z",
+                "authorization": { "type": "deviceCode", "deviceCode": "abc" }
+            }"#,
+        );
+
+        assert!(
+            matches!(result, Err(ClientError::TextError(message)) if message.contains("version 2"))
+        );
+    }
+}
@@ -1,16 +1,52 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    ClientError,
+    ClientError, Secret,
+    profile::Profile,
     receipt::{Receipt, ReceiptSummary},
 };
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthState {
+    /// No token is present.
+    Unauthenticated,
+    /// An anonymous token is present, which only grants access to public data.
+    Anonymous,
+    /// A member token is present and can be used or refreshed.
+    Authenticated,
+    /// The refresh token was rejected by the provider and a new login is required.
+    Expired,
+}
+
+/// Also the `authorization` of an [`AuthenticatorMessage`](crate::authenticator::AuthenticatorMessage).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase", tag = "type")]
+pub enum Authorization {
+    #[serde(rename_all = "camelCase")]
+    Code {
+        code: Secret<String>,
+        code_verifier: Option<Secret<String>>,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    RefreshToken { refresh_token: Secret<String> },
+}
+
 pub trait Identifier {
     fn identifier(&self) -> String;
 }
 
 #[async_trait]
 pub trait Client {
+    fn provider(&self) -> &'static str;
+
+    async fn auth_state(&self) -> AuthState;
+
+    async fn authorize(&self, authorization: Authorization) -> Result<(), ClientError>;
+
+    async fn profile(&self) -> Result<Profile, ClientError>;
+
     async fn receipts(&self) -> Result<Vec<ReceiptSummary>, ClientError>;
 
     async fn receipt(&self, receipt_id: &str) -> Result<Receipt, ClientError>;
@@ -3,4 +3,6 @@ pub enum ClientError {
     RequestError(reqwest::Error),
     JsonError(serde_json::Value),
     TextError(String),
+    /// Non-success response. A body that is not JSON is kept as a JSON string.
+    StatusError(reqwest::StatusCode, serde_json::Value),
 }
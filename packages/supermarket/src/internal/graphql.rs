@@ -13,6 +13,7 @@ pub enum GraphQLClientError {
     RequestError(reqwest::Error),
     JsonError(serde_json::Value),
     TextError(String),
+    StatusError(reqwest::StatusCode, serde_json::Value),
     GraphQLError(Vec<graphql_client::Error>),
 }
 
@@ -22,6 +23,21 @@ impl From<ClientError> for GraphQLClientError {
             ClientError::RequestError(e) => GraphQLClientError::RequestError(e),
             ClientError::JsonError(e) => GraphQLClientError::JsonError(e),
             ClientError::TextError(e) => GraphQLClientError::TextError(e),
+            ClientError::StatusError(status, e) => GraphQLClientError::StatusError(status, e),
+        }
+    }
+}
+
+impl From<GraphQLClientError> for ClientError {
+    fn from(error: GraphQLClientError) -> Self {
+        match error {
+            GraphQLClientError::RequestError(e) => ClientError::RequestError(e),
+            GraphQLClientError::JsonError(e) => ClientError::JsonError(e),
+            GraphQLClientError::TextError(e) => ClientError::TextError(e),
+            GraphQLClientError::StatusError(status, e) => ClientError::StatusError(status, e),
+            GraphQLClientError::GraphQLError(e) => {
+                ClientError::JsonError(serde_json::to_value(e).unwrap_or_default())
+            }
         }
     }
 }
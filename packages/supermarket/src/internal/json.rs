@@ -23,13 +23,13 @@ impl JsonClient {
         JsonClient { client, url, auth }
     }
 
-    pub async fn request<Q: Serialize, B: Serialize, R: DeserializeOwned>(
+    pub async fn send<Q: Serialize, B: Serialize>(
         &self,
         method: Method,
         url: &str,
         query: Q,
         body: B,
-    ) -> Result<R, ClientError> {
+    ) -> Result<reqwest::Response, ClientError> {
         let mut request_builder = self.client.request(method, format!("{}{}", self.url, url));
 
         {
@@ -44,28 +44,45 @@ impl JsonClient {
             Err(e) => return Err(ClientError::RequestError(e)),
         };
 
-        if response.status().is_success() {
-            match response.json::<R>().await {
-                Ok(response) => Ok(response),
-                Err(e) => Err(ClientError::RequestError(e)),
-            }
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(response)
         } else {
             if let Some(value) = response.headers().get("content-type")
                 && value.to_str().unwrap_or("") == "application/json"
             {
                 return match response.json::<serde_json::Value>().await {
-                    Ok(response) => Err(ClientError::JsonError(response)),
+                    Ok(response) => Err(ClientError::StatusError(status, response)),
                     Err(e) => Err(ClientError::RequestError(e)),
                 };
             }
 
             match response.text().await {
-                Ok(response) => Err(ClientError::TextError(response)),
+                Ok(response) => Err(ClientError::StatusError(
+                    status,
+                    serde_json::Value::String(response),
+                )),
                 Err(e) => Err(ClientError::RequestError(e)),
             }
         }
     }
 
+    pub async fn request<Q: Serialize, B: Serialize, R: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        query: Q,
+        body: B,
+    ) -> Result<R, ClientError> {
+        let response = self.send(method, url, query, body).await?;
+
+        match response.json::<R>().await {
+            Ok(response) => Ok(response),
+            Err(e) => Err(ClientError::RequestError(e)),
+        }
+    }
+
     pub async fn delete<Q: Serialize, B: Serialize, R: DeserializeOwned>(
         &self,
         url: &str,
@@ -1,13 +1,22 @@
+pub mod authenticator;
 mod client;
 pub mod credentials;
 pub mod internal;
 pub mod product;
+pub mod profile;
+pub mod purchase;
 pub mod receipt;
+pub mod secret;
 pub mod serde;
+pub mod session;
+pub mod token;
 
+pub use client::AuthState;
+pub use client::Authorization;
 pub use client::Client;
 pub use client::Identifier;
 pub use internal::ClientError;
+pub use secret::Secret;
 
 #[cfg(test)]
 mod tests {
@@ -1,10 +1,45 @@
+use crate::client::Identifier;
+
 #[derive(Debug)]
 pub struct Product {
     pub id: String,
     pub name: String,
+    pub brand: Option<String>,
+    pub gtin: Option<String>,
+    pub ingredients: Option<String>,
+    pub allergens: ProductAllergens,
+    pub nutrition: Vec<ProductNutrition>,
     pub restrictions: Vec<ProductRestriction>,
 }
 
+impl Identifier for Product {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ProductAllergens {
+    pub contains: Vec<String>,
+    pub may_contain: Vec<String>,
+    pub free_from: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ProductNutrition {
+    pub basis: Option<String>,
+    pub preparation_state: Option<String>,
+    pub nutrients: Vec<ProductNutrient>,
+}
+
+#[derive(Debug)]
+pub struct ProductNutrient {
+    pub r#type: String,
+    pub name: String,
+    pub value: String,
+    pub daily_value: Option<String>,
+}
+
 #[derive(Debug)]
 pub enum ProductRestriction {
     AgeRestriction(u8),
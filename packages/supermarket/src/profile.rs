@@ -0,0 +1,40 @@
+use crate::client::Identifier;
+
+#[derive(Debug)]
+pub struct Profile {
+    pub id: String,
+    pub email_address: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone_numbers: Vec<String>,
+    pub addresses: Vec<ProfileAddress>,
+    pub cards: Vec<ProfileCard>,
+    pub preferences: Vec<ProfilePreference>,
+}
+
+impl Identifier for Profile {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct ProfileAddress {
+    pub street: Option<String>,
+    pub house_number: Option<String>,
+    pub postal_code: Option<String>,
+    pub city: Option<String>,
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ProfileCard {
+    pub r#type: String,
+    pub number: String,
+}
+
+#[derive(Debug)]
+pub struct ProfilePreference {
+    pub code: String,
+    pub granted: bool,
+}
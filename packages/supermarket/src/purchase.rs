@@ -0,0 +1,16 @@
+use crate::client::Identifier;
+
+/// A product from the member's purchase history, where `rank` is its position when sorted by purchase frequency (0 is most frequent).
+#[derive(Debug)]
+pub struct PurchasedProduct {
+    pub id: String,
+    pub name: String,
+    pub brand: Option<String>,
+    pub rank: usize,
+}
+
+impl Identifier for PurchasedProduct {
+    fn identifier(&self) -> String {
+        self.id.clone()
+    }
+}
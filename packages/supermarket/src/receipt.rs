@@ -20,6 +20,7 @@ pub struct Receipt {
     pub id: String,
     pub created_at: DateTime<Utc>,
     pub products: Vec<ReceiptProduct>,
+    pub vat: Vec<ReceiptVat>,
 }
 
 impl Identifier for Receipt {
@@ -30,3 +31,11 @@ impl Identifier for Receipt {
 
 #[derive(Debug)]
 pub struct ReceiptProduct {}
+
+/// VAT paid at a single rate, with the rate as a percentage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceiptVat {
+    pub rate: f64,
+    pub base: f64,
+    pub amount: f64,
+}
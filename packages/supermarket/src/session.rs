@@ -0,0 +1,160 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use crate::{
+    Authorization, Client, ClientError,
+    receipt::{Receipt, ReceiptSummary},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Account {
+    pub provider: String,
+    pub name: String,
+}
+
+impl Account {
+    pub fn new(provider: &str, name: &str) -> Self {
+        Account {
+            provider: provider.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Display for Account {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.provider, self.name)
+    }
+}
+
+#[derive(Debug)]
+pub struct AccountResult<T> {
+    pub account: Account,
+    pub result: Result<T, ClientError>,
+}
+
+struct Session {
+    client: Arc<dyn Client + Send + Sync>,
+    any: Arc<dyn Any + Send + Sync>,
+}
+
+pub struct SessionManager {
+    sessions: BTreeMap<Account, Session>,
+}
+
+impl SessionManager {
+    pub fn new() -> Self {
+        SessionManager {
+            sessions: BTreeMap::new(),
+        }
+    }
+
+    /// Fails when the account already has a session; [`SessionManager::remove`] it first to replace it.
+    pub fn add<C: Client + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        client: C,
+    ) -> Result<Account, ClientError> {
+        let account = Account::new(client.provider(), name);
+
+        if self.sessions.contains_key(&account) {
+            return Err(ClientError::TextError(format!(
+                "Account {account} already exists"
+            )));
+        }
+
+        let client = Arc::new(client);
+
+        self.sessions.insert(
+            account.clone(),
+            Session {
+                client: Arc::clone(&client) as Arc<dyn Client + Send + Sync>,
+                any: client as Arc<dyn Any + Send + Sync>,
+            },
+        );
+
+        Ok(account)
+    }
+
+    pub fn remove(&mut self, account: &Account) -> bool {
+        self.sessions.remove(account).is_some()
+    }
+
+    pub fn accounts(&self) -> Vec<Account> {
+        self.sessions.keys().cloned().collect()
+    }
+
+    pub fn provider_accounts(&self, provider: &str) -> Vec<Account> {
+        self.sessions
+            .keys()
+            .filter(|account| account.provider == provider)
+            .cloned()
+            .collect()
+    }
+
+    pub fn client(&self, account: &Account) -> Option<Arc<dyn Client + Send + Sync>> {
+        self.sessions
+            .get(account)
+            .map(|session| Arc::clone(&session.client))
+    }
+
+    pub fn typed_client<C: Client + Send + Sync + 'static>(
+        &self,
+        account: &Account,
+    ) -> Option<Arc<C>> {
+        self.sessions
+            .get(account)
+            .and_then(|session| Arc::clone(&session.any).downcast::<C>().ok())
+    }
+
+    pub async fn authorize(
+        &self,
+        account: &Account,
+        authorization: Authorization,
+    ) -> Result<(), ClientError> {
+        match self.sessions.get(account) {
+            Some(session) => session.client.authorize(authorization).await,
+            None => Err(ClientError::TextError(format!("Unknown account {account}"))),
+        }
+    }
+
+    pub async fn run<T, F>(&self, operation: F) -> Vec<AccountResult<T>>
+    where
+        F: AsyncFn(&(dyn Client + Send + Sync)) -> Result<T, ClientError>,
+    {
+        let mut results = Vec::with_capacity(self.sessions.len());
+
+        for (account, session) in &self.sessions {
+            results.push(AccountResult {
+                account: account.clone(),
+                result: operation(session.client.as_ref()).await,
+            });
+        }
+
+        results
+    }
+
+    pub async fn receipts(&self) -> Vec<AccountResult<Vec<ReceiptSummary>>> {
+        self.run(async |client| client.receipts().await).await
+    }
+
+    pub async fn receipt(
+        &self,
+        account: &Account,
+        receipt_id: &str,
+    ) -> Option<Result<Receipt, ClientError>> {
+        let session = self.sessions.get(account)?;
+
+        Some(session.client.receipt(receipt_id).await)
+    }
+}
+
+impl Default for SessionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
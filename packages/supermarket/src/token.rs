@@ -0,0 +1,185 @@
+use std::{
+    ffi::OsString,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    marker::PhantomData,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::ClientError;
+
+pub trait TokenStore<T>: Send + Sync {
+    /// Returns `None` when no token has been saved, and an error when the stored token cannot be read.
+    fn load(&self) -> Result<Option<T>, ClientError>;
+
+    fn save(&self, token: &T) -> Result<(), ClientError>;
+
+    fn clear(&self) -> Result<(), ClientError>;
+}
+
+pub struct MemoryTokenStore<T> {
+    token: Mutex<Option<T>>,
+}
+
+impl<T> MemoryTokenStore<T> {
+    pub fn new() -> Self {
+        MemoryTokenStore {
+            token: Mutex::new(None),
+        }
+    }
+
+    pub fn with_token(token: T) -> Self {
+        MemoryTokenStore {
+            token: Mutex::new(Some(token)),
+        }
+    }
+}
+
+impl<T: Clone + Send> TokenStore<T> for MemoryTokenStore<T> {
+    fn load(&self) -> Result<Option<T>, ClientError> {
+        Ok(self
+            .token
+            .lock()
+            .expect("Lock should not be poisoned")
+            .clone())
+    }
+
+    fn save(&self, token: &T) -> Result<(), ClientError> {
+        *self.token.lock().expect("Lock should not be poisoned") = Some(token.clone());
+
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), ClientError> {
+        *self.token.lock().expect("Lock should not be poisoned") = None;
+
+        Ok(())
+    }
+}
+
+impl<T> Default for MemoryTokenStore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct FileTokenStore<T> {
+    path: PathBuf,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> FileTokenStore<T> {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileTokenStore {
+            path: path.into(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> FileTokenStore<T> {
+    fn temp_path(&self) -> PathBuf {
+        let mut file_name = OsString::from(".");
+        file_name.push(self.path.file_name().unwrap_or_default());
+        file_name.push(".tmp");
+
+        self.path.with_file_name(file_name)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> TokenStore<T> for FileTokenStore<T> {
+    fn load(&self) -> Result<Option<T>, ClientError> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(ClientError::TextError(e.to_string())),
+        };
+
+        serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| ClientError::TextError(e.to_string()))
+    }
+
+    /// Writes the token as JSON to a temporary file next to it, which then replaces the old file, so
+    /// an interrupted save never leaves a partial token behind. On Unix the file is only readable by
+    /// the owner, as it contains secrets.
+    fn save(&self, token: &T) -> Result<(), ClientError> {
+        let contents =
+            serde_json::to_string(token).map_err(|e| ClientError::TextError(e.to_string()))?;
+        let temp_path = self.temp_path();
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+
+            options.mode(0o600);
+        }
+
+        let mut file = options
+            .open(&temp_path)
+            .map_err(|e| ClientError::TextError(e.to_string()))?;
+
+        // The mode only applies to new files, so also restrict a temporary file left behind by a crash.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            file.set_permissions(fs::Permissions::from_mode(0o600))
+                .map_err(|e| ClientError::TextError(e.to_string()))?;
+        }
+
+        file.write_all(contents.as_bytes())
+            .and_then(|_| file.sync_all())
+            .and_then(|_| fs::rename(&temp_path, &self.path))
+            .map_err(|e| ClientError::TextError(e.to_string()))
+    }
+
+    fn clear(&self) -> Result<(), ClientError> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(ClientError::TextError(e.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(name: &str) -> FileTokenStore<String> {
+        let path = std::env::temp_dir().join(format!("supermarket-{}-{name}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        FileTokenStore::new(path)
+    }
+
+    #[test]
+    fn file_token_store_round_trip() {
+        let store = store("round-trip");
+
+        assert!(store.load().unwrap().is_none());
+
+        store.save(&String::from("token")).unwrap();
+        assert_eq!(store.load().unwrap().as_deref(), Some("token"));
+        assert!(!store.temp_path().exists());
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn file_token_store_corrupt() {
+        let store = store("corrupt");
+
+        fs::write(&store.path, "{\"access_").unwrap();
+        assert!(store.load().is_err());
+
+        store.clear().unwrap();
+    }
+}